@@ -4,9 +4,15 @@ fn main() {
     // Once the MSRV is 1.74, we can replace this with a lint in Cargo.toml instead.
     println!("cargo:rustc-check-cfg=cfg(doc_cfg)");
     println!("cargo:rustc-check-cfg=cfg(const_mut_refs)");
+    println!("cargo:rustc-check-cfg=cfg(const_float_arithmetic)");
 
-    // Rust 1.83+ support &mut in const fn.
     if let Some(compiler) = rustc_version() {
+        // Rust 1.82+ supports floating point arithmetic in const fn.
+        if compiler.is_newer_than_minor(82) {
+            println!("cargo:rustc-cfg=const_float_arithmetic");
+        }
+
+        // Rust 1.83+ support &mut in const fn.
         if compiler.is_newer_than_minor(83) {
             println!("cargo:rustc-cfg=const_mut_refs");
         }
@@ -1,7 +1,8 @@
-use owo_colors::OwoColorize;
 use owo_colors::colors::CustomColor;
+use owo_colors::{OwoColorize, hex};
 
 fn main() {
     println!("{}", "custom purple".fg::<CustomColor<141, 59, 212>>());
     println!("{}", "custom green".fg_rgb::<50, 209, 42>());
+    println!("{}", "custom blue".fg::<hex!("#2E3192")>());
 }
@@ -0,0 +1,32 @@
+use owo_colors::{OwoColorize, Rgb};
+
+const OWO: &str = r#"
+ ██████╗ ██╗    ██╗ ██████╗
+██╔═══██╗██║    ██║██╔═══██╗
+██║   ██║██║ █╗ ██║██║   ██║
+██║   ██║██║███╗██║██║   ██║
+╚██████╔╝╚███╔███╔╝╚██████╔╝
+ ╚═════╝  ╚══╝╚══╝  ╚═════╝"#;
+
+fn main() {
+    let stops = [
+        Rgb(0xB8, 0x0A, 0x41),
+        Rgb(0x4E, 0x4B, 0xA8),
+        Rgb(0x00, 0x93, 0x8A),
+    ];
+    println!("{}\n", OWO.gradient_stops(&stops).bold());
+
+    for percent in [10, 45, 80, 100] {
+        let bar = format!("{:<20}", "█".repeat(percent / 5));
+        println!(
+            "[{}] {:>3}%",
+            bar.gradient(Rgb(0xE2, 0x38, 0x38), Rgb(0x6E, 0xB1, 0x22)),
+            percent
+        );
+    }
+
+    println!(
+        "\n{}",
+        " headers too ".on_gradient(Rgb(0x2E, 0x31, 0x92), Rgb(0xDA, 0xAC, 0x06))
+    );
+}
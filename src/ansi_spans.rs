@@ -0,0 +1,227 @@
+//! Parsing text containing escape sequences back into styled spans.
+use crate::escapes::EscapeScanner;
+use crate::sgr::apply_sgr_lossy;
+use crate::{AnsiColors, DynColors, Font, Style, Styled};
+
+#[cfg(feature = "alloc")]
+use crate::StyledVec;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// An iterator over the styled spans of text containing SGR escape sequences, such as the
+/// colored output of another program. Created by [`ansi_spans`].
+#[derive(Clone, Debug)]
+pub struct AnsiSpans<'a> {
+    text: &'a str,
+    style: Style,
+}
+
+/// Splits text containing SGR escape sequences into spans of text, each with the [`Style`] it
+/// would be displayed with in a terminal.
+///
+/// The style is kept across escape sequences, so that `\x1b[1m` followed by `\x1b[31m` gives bold
+/// red text, and codes such as `22` (neither bold nor dimmed) and `39` (default foreground color)
+/// remove what they turn off. Codes which aren't supported are skipped, as are other escape
+/// sequences, such as cursor movements and hyperlinks. The spans don't contain any escape
+/// sequences, and may be empty only if the text is.
+///
+/// See [`parse_ansi`] to collect the spans into a [`StyledList`](crate::StyledList).
+///
+/// ```rust
+/// use owo_colors::{Style, ansi_spans};
+///
+/// let text = "\x1b[1mwarning\x1b[0m: unused \x1b[1;33mvariable\x1b[22m `x`\x1b[m";
+/// let spans: Vec<_> = ansi_spans(text)
+///     .map(|span| (span.style, *span.inner()))
+///     .collect();
+/// assert_eq!(
+///     spans,
+///     [
+///         (Style::new().bold(), "warning"),
+///         (Style::new(), ": unused "),
+///         (Style::new().bold().yellow(), "variable"),
+///         (Style::new().yellow(), " `x`"),
+///     ]
+/// );
+/// ```
+pub fn ansi_spans(text: &str) -> AnsiSpans<'_> {
+    AnsiSpans {
+        text,
+        style: Style::new(),
+    }
+}
+
+/// Parses text containing SGR escape sequences into a [`StyledVec`], which can be modified and
+/// displayed again. See [`ansi_spans`] for how the text is parsed.
+///
+/// ```rust
+/// use owo_colors::{Style, parse_ansi};
+///
+/// let mut list = parse_ansi("\x1b[31merror\x1b[0m: \x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\");
+/// assert_eq!(list.0.len(), 3);
+///
+/// // Re-theme the output
+/// for span in &mut list.0 {
+///     if span.style.get_fg().is_some() {
+///         span.style = Style::new().bright_magenta().bold();
+///     }
+/// }
+/// assert_eq!(list.to_string(), "\x1b[95;1merror\x1b[0m: docs");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_ansi(text: &str) -> StyledVec<&str> {
+    ansi_spans(text).collect::<alloc::vec::Vec<_>>().into()
+}
+
+impl<'a> AnsiSpans<'a> {
+    /// Returns the style of the text after the escape sequences parsed so far.
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Applies the escape sequences in `escapes`, which may contain several of them if a control
+    /// string is ended by the start of the next sequence.
+    fn apply(&mut self, escapes: &str) {
+        let last = escapes.rfind(['\x1b', '\u{9b}']).unwrap_or(0);
+        let sequence = &escapes[last..];
+        let params = sequence
+            .strip_prefix("\x1b[")
+            .or_else(|| sequence.strip_prefix('\u{9b}'))
+            .and_then(|sequence| sequence.strip_suffix('m'));
+
+        // Sequences with private parameters or intermediate bytes aren't SGR sequences
+        if let Some(params) = params.filter(|params| {
+            params
+                .bytes()
+                .all(|c| c.is_ascii_digit() || c == b';' || c == b':')
+        }) {
+            let mut style = apply_sgr_lossy(self.style, params);
+            // Setting the defaults is the same as not setting anything, so that the style can
+            // become plain again
+            let default = Some(DynColors::Ansi(AnsiColors::Default));
+            for color in [&mut style.fg, &mut style.bg, &mut style.underline_color] {
+                if *color == default {
+                    *color = None;
+                }
+            }
+            if style.style_flags.font() == Some(Font::Primary) {
+                style.style_flags = style.style_flags.set_font(None);
+            }
+            self.style = style;
+        }
+    }
+}
+
+impl<'a> Iterator for AnsiSpans<'a> {
+    type Item = Styled<&'a str>;
+
+    fn next(&mut self) -> Option<Styled<&'a str>> {
+        while !self.text.is_empty() {
+            let mut scanner = EscapeScanner::default();
+            let text_end = self
+                .text
+                .char_indices()
+                .find(|&(_, c)| scanner.feed(c))
+                .map_or(self.text.len(), |(i, _)| i);
+            if text_end > 0 {
+                let (text, rest) = self.text.split_at(text_end);
+                self.text = rest;
+                return Some(self.style.style(text));
+            }
+
+            // The text starts with an escape sequence, which ends when the scanner is back on
+            // the ground. An unfinished sequence takes up the rest of the text.
+            let mut scanner = EscapeScanner::default();
+            let escapes_end = self
+                .text
+                .char_indices()
+                .find(|&(_, c)| {
+                    scanner.feed(c);
+                    scanner.is_ground()
+                })
+                .map_or(self.text.len(), |(i, c)| i + c.len_utf8());
+            let (escapes, rest) = self.text.split_at(escapes_end);
+            self.text = rest;
+            self.apply(escapes);
+        }
+        None
+    }
+}
+
+impl core::iter::FusedIterator for AnsiSpans<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::XtermColors;
+    use std::vec::Vec;
+
+    fn spans(text: &str) -> Vec<(Style, &str)> {
+        ansi_spans(text)
+            .map(|span| (span.style, *span.inner()))
+            .collect()
+    }
+
+    #[test]
+    fn test_ansi_spans() {
+        assert_eq!(spans(""), []);
+        assert_eq!(spans("plain"), [(Style::new(), "plain")]);
+        assert_eq!(
+            spans("\x1b[38;5;208;4:3mwavy\x1b[24;39m\x1b[48:2::1:2:3m\x1b[Kbg\x1b[49mend"),
+            [
+                (
+                    Style::new()
+                        .color(XtermColors::FlushOrange)
+                        .curly_underline(),
+                    "wavy"
+                ),
+                (Style::new().on_truecolor(1, 2, 3), "bg"),
+                (Style::new(), "end"),
+            ]
+        );
+
+        // Unsupported codes are skipped, and the rest of the sequence still applies
+        assert_eq!(
+            spans("\x1b[1;26;31ma\x1b[?25l\x1b[0;60mb"),
+            [(Style::new().bold().red(), "a"), (Style::new(), "b")]
+        );
+
+        // Other escape sequences don't split the text into spans with different styles
+        assert_eq!(
+            spans("\x1b[32mti\x1b]0;title\x07tle\x1b]2;x\x1b[1m!\x1b(B\u{9b}0m"),
+            [
+                (Style::new().green(), "ti"),
+                (Style::new().green(), "tle"),
+                (Style::new().green().bold(), "!"),
+            ]
+        );
+
+        // An unfinished sequence at the end is dropped
+        assert_eq!(spans("é\x1b[3"), [(Style::new(), "é")]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_ansi_round_trip() {
+        use crate::StyledList;
+        use std::string::ToString;
+
+        let list = StyledList::from([
+            Style::new().red().bold().style("a"),
+            Style::new().red().italic().style("b"),
+            Style::new().style("c"),
+            Style::new()
+                .underline_truecolor(1, 2, 3)
+                .strikethrough()
+                .style("d"),
+        ]);
+        let text = list.to_string();
+        let parsed = parse_ansi(&text);
+        assert_eq!(parsed.to_string(), text);
+        assert_eq!(
+            parsed.0.iter().map(|span| span.style).collect::<Vec<_>>(),
+            list.0.iter().map(|span| span.style).collect::<Vec<_>>()
+        );
+    }
+}
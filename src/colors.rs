@@ -1,5 +1,7 @@
 //! Color types for used for being generic over the color
-use crate::{BgColorDisplay, BgDynColorDisplay, FgColorDisplay, FgDynColorDisplay};
+use crate::{
+    BgColorDisplay, BgDynColorDisplay, FgColorDisplay, FgDynColorDisplay, UnderlineColorDisplay,
+};
 use core::fmt;
 
 macro_rules! colors {
@@ -126,13 +128,45 @@ colors! {
     BrightWhite   97 107,
 }
 
+/// Writes the foreground ANSI code of a compile-time color, downgrading it if the stream currently
+/// being displayed doesn't support it.
+#[inline(always)]
+pub(crate) fn fmt_ansi_fg<C: crate::Color>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if_downgraded!(C::DYN_COLORS_EQUIVALENT, fmt_ansi_fg(f));
+    f.write_str(C::ANSI_FG)
+}
+
+/// Writes the background ANSI code of a compile-time color, downgrading it if the stream currently
+/// being displayed doesn't support it.
+#[inline(always)]
+pub(crate) fn fmt_ansi_bg<C: crate::Color>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if_downgraded!(C::DYN_COLORS_EQUIVALENT, fmt_ansi_bg(f));
+    f.write_str(C::ANSI_BG)
+}
+
+/// Writes the raw foreground ANSI code of a compile-time color, downgrading it if the stream
+/// currently being displayed doesn't support it.
+#[inline(always)]
+pub(crate) fn fmt_raw_ansi_fg<C: crate::Color>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if_downgraded!(C::DYN_COLORS_EQUIVALENT, fmt_raw_ansi_fg(f));
+    f.write_str(C::RAW_ANSI_FG)
+}
+
+/// Writes the raw background ANSI code of a compile-time color, downgrading it if the stream
+/// currently being displayed doesn't support it.
+#[inline(always)]
+pub(crate) fn fmt_raw_ansi_bg<C: crate::Color>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if_downgraded!(C::DYN_COLORS_EQUIVALENT, fmt_raw_ansi_bg(f));
+    f.write_str(C::RAW_ANSI_BG)
+}
+
 macro_rules! impl_fmt_for {
     ($($trait:path),* $(,)?) => {
         $(
             impl<'a, Color: crate::Color, T: ?Sized + $trait> $trait for FgColorDisplay<'a, Color, T> {
                 #[inline(always)]
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    f.write_str(Color::ANSI_FG)?;
+                    fmt_ansi_fg::<Color>(f)?;
                     <T as $trait>::fmt(&self.0, f)?;
                     f.write_str("\x1b[39m")
                 }
@@ -141,7 +175,7 @@ macro_rules! impl_fmt_for {
             impl<'a, Color: crate::Color, T: ?Sized + $trait> $trait for BgColorDisplay<'a, Color, T> {
                 #[inline(always)]
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    f.write_str(Color::ANSI_BG)?;
+                    fmt_ansi_bg::<Color>(f)?;
                     <T as $trait>::fmt(&self.0, f)?;
                     f.write_str("\x1b[49m")
                 }
@@ -182,6 +216,17 @@ macro_rules! impl_fmt_for_dyn {
                     f.write_str("\x1b[49m")
                 }
             }
+
+            impl<'a, Color: crate::DynColor, T: ?Sized + $trait> $trait for UnderlineColorDisplay<'a, Color, T> {
+                #[inline(always)]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("\x1b[")?;
+                    (self.1).get_dyncolors_fg().fmt_raw_ansi_underline(f)?;
+                    f.write_str("m")?;
+                    <T as $trait>::fmt(&self.0, f)?;
+                    f.write_str("\x1b[59m")
+                }
+            }
         )*
     };
 }
@@ -210,4 +255,10 @@ mod custom;
 
 pub use custom::CustomColor;
 
+mod adjust;
 pub(crate) mod dynamic;
+mod math;
+pub(crate) mod oklab;
+pub(crate) mod palette;
+pub(crate) mod quantize;
+mod spaces;
@@ -0,0 +1,606 @@
+//! Deriving colors from other colors, and checking the contrast between them.
+use super::math::clamp_unit;
+use super::oklab::{Oklab, srgb_to_linear};
+use crate::{AnsiColors, DynColors, Palette, Rgb, XtermColors};
+
+impl Rgb {
+    /// Makes this color lighter by adding `amount` to its perceived lightness, which ranges from 0
+    /// (black) to 1 (white). The hue is kept the same.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// let blue = Rgb(46, 49, 146);
+    /// assert_eq!(blue.lighten(0.2), Rgb(96, 110, 212));
+    /// assert_eq!(blue.lighten(1.0), Rgb(255, 255, 255));
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Rgb {
+        let lab = Oklab::from_rgb(self);
+        Oklab {
+            l: clamp_unit(lab.l + amount),
+            ..lab
+        }
+        .to_rgb()
+    }
+
+    /// Makes this color darker by subtracting `amount` from its perceived lightness. See
+    /// [`Rgb::lighten`].
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb(255, 0, 0).darken(0.2), Rgb(153, 0, 0));
+    /// assert_eq!(Rgb(255, 0, 0).darken(1.0), Rgb(0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Rgb {
+        self.lighten(-amount)
+    }
+
+    /// Makes this color more colorful by scaling its chroma by `1 + amount`, keeping its perceived
+    /// lightness and hue.
+    ///
+    /// Colors that would become too colorful to represent in RGB are made as colorful as possible
+    /// instead.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb(150, 100, 100).saturate(1.0), Rgb(179, 79, 84));
+    /// ```
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Rgb {
+        let lab = Oklab::from_rgb(self);
+        let scale = (1.0 + amount).max(0.0);
+        Oklab {
+            l: lab.l,
+            a: lab.a * scale,
+            b: lab.b * scale,
+        }
+        .to_rgb()
+    }
+
+    /// Makes this color less colorful by scaling its chroma by `1 - amount`, keeping its perceived
+    /// lightness and hue. An `amount` of 1 results in a gray.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb(255, 0, 0).desaturate(1.0), Rgb(136, 136, 136));
+    /// ```
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Rgb {
+        self.saturate(-amount)
+    }
+
+    /// Mixes this color with `other`, where a `t` of 0 returns this color and a `t` of 1 returns
+    /// `other`.
+    ///
+    /// Colors are mixed in the [OKLab](https://bottosson.github.io/posts/oklab/) color space, so
+    /// that the result looks evenly in-between.
+    ///
+    /// ```rust
+    /// use owo_colors::{CssColors, Rgb};
+    ///
+    /// let black = Rgb(0, 0, 0);
+    /// assert_eq!(black.mix(CssColors::White, 0.5), Rgb(99, 99, 99));
+    /// assert_eq!(black.mix(CssColors::White, 0.0), black);
+    /// ```
+    #[must_use]
+    pub fn mix(self, other: impl Into<Rgb>, t: f32) -> Rgb {
+        Oklab::from_rgb(self)
+            .mix(Oklab::from_rgb(other.into()), clamp_unit(t))
+            .to_rgb()
+    }
+
+    /// Returns the relative luminance of this color, from 0 (black) to 1 (white), as defined by
+    /// [WCAG 2](https://www.w3.org/TR/WCAG22/#dfn-relative-luminance).
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb(255, 255, 255).relative_luminance(), 1.0);
+    /// assert!((Rgb(255, 0, 0).relative_luminance() - 0.2126).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn relative_luminance(self) -> f32 {
+        let Rgb(r, g, b) = self;
+        0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+    }
+
+    /// Returns the [WCAG 2 contrast ratio](https://www.w3.org/TR/WCAG22/#dfn-contrast-ratio)
+    /// between this color and `other`, from 1 (no contrast) to 21 (black and white).
+    ///
+    /// WCAG requires a ratio of at least 4.5 for normal text (7 for its enhanced level).
+    ///
+    /// ```rust
+    /// use owo_colors::{CssColors, Rgb};
+    ///
+    /// assert!((Rgb(0, 0, 0).contrast_ratio(CssColors::White) - 21.0).abs() < 1e-4);
+    /// assert!(Rgb(118, 118, 118).contrast_ratio(CssColors::White) >= 4.5);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(self, other: impl Into<Rgb>) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.into().relative_luminance());
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Returns black or white, whichever is more readable on top of this color.
+    ///
+    /// One of the two always has a [contrast ratio](Rgb::contrast_ratio) of at least 4.58 with
+    /// this color, which passes WCAG's AA level for normal text.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb(255, 220, 0).readable_foreground(), Rgb(0, 0, 0));
+    /// assert_eq!(Rgb(46, 49, 146).readable_foreground(), Rgb(255, 255, 255));
+    /// ```
+    #[must_use]
+    pub fn readable_foreground(self) -> Rgb {
+        const BLACK: Rgb = Rgb(0, 0, 0);
+        const WHITE: Rgb = Rgb(255, 255, 255);
+
+        if self.contrast_ratio(BLACK) >= self.contrast_ratio(WHITE) {
+            BLACK
+        } else {
+            WHITE
+        }
+    }
+}
+
+impl DynColors {
+    /// Applies `f` to the RGB value of this color, or returns the color unchanged if it doesn't
+    /// have one.
+    fn map_rgb(self, f: impl FnOnce(Rgb) -> Rgb) -> DynColors {
+        match self.to_rgb() {
+            Some(rgb) => {
+                let Rgb(r, g, b) = f(rgb);
+                DynColors::Rgb(r, g, b)
+            }
+            None => self,
+        }
+    }
+
+    /// Applies `f` to the RGB value of this color as a foreground displayed using `palette`.
+    fn map_rgb_with(self, palette: &Palette, f: impl FnOnce(Rgb) -> Rgb) -> DynColors {
+        let Rgb(r, g, b) = f(palette.resolve_fg(self));
+        DynColors::Rgb(r, g, b)
+    }
+
+    /// Makes this color lighter. See [`Rgb::lighten`].
+    ///
+    /// The result is an RGB color, found using [`DynColors::to_rgb`].
+    /// [`AnsiColors::Default`](crate::AnsiColors::Default) is returned unchanged.
+    ///
+    /// ```rust
+    /// use owo_colors::{CssColors, DynColors};
+    ///
+    /// let teal = DynColors::Css(CssColors::Teal);
+    /// assert_eq!(teal.lighten(0.1), DynColors::Rgb(56, 159, 158));
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> DynColors {
+        self.map_rgb(|rgb| rgb.lighten(amount))
+    }
+
+    /// Makes this color darker. See [`Rgb::darken`] and [`DynColors::lighten`].
+    #[must_use]
+    pub fn darken(self, amount: f32) -> DynColors {
+        self.map_rgb(|rgb| rgb.darken(amount))
+    }
+
+    /// Makes this color more colorful. See [`Rgb::saturate`] and [`DynColors::lighten`].
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> DynColors {
+        self.map_rgb(|rgb| rgb.saturate(amount))
+    }
+
+    /// Makes this color less colorful. See [`Rgb::desaturate`] and [`DynColors::lighten`].
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> DynColors {
+        self.map_rgb(|rgb| rgb.desaturate(amount))
+    }
+
+    /// Mixes this color with `other`. See [`Rgb::mix`] and [`DynColors::lighten`].
+    ///
+    /// If `other` is [`AnsiColors::Default`](crate::AnsiColors::Default), this color is returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, XtermColors};
+    ///
+    /// let red = DynColors::Xterm(XtermColors::Red);
+    /// let blue = DynColors::Ansi(AnsiColors::Blue);
+    /// assert_eq!(red.mix(blue, 1.0), DynColors::Rgb(0, 0, 128));
+    /// ```
+    #[must_use]
+    pub fn mix(self, other: DynColors, t: f32) -> DynColors {
+        match other.to_rgb() {
+            Some(other) => self.map_rgb(|rgb| rgb.mix(other, t)),
+            None => self,
+        }
+    }
+
+    /// Makes this color lighter, when the ANSI colors are displayed using `palette`. See
+    /// [`Rgb::lighten`].
+    ///
+    /// The result is an RGB color, found using [`Palette::resolve_fg`], so
+    /// [`AnsiColors::Default`] is the palette's foreground color.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette};
+    ///
+    /// let text = DynColors::Ansi(AnsiColors::Default);
+    /// assert_eq!(text.lighten_with(0.0, &Palette::SOLARIZED_DARK), DynColors::Rgb(131, 148, 150));
+    /// ```
+    #[must_use]
+    pub fn lighten_with(self, amount: f32, palette: &Palette) -> DynColors {
+        self.map_rgb_with(palette, |rgb| rgb.lighten(amount))
+    }
+
+    /// Makes this color darker, when the ANSI colors are displayed using `palette`. See
+    /// [`Rgb::darken`] and [`DynColors::lighten_with`].
+    #[must_use]
+    pub fn darken_with(self, amount: f32, palette: &Palette) -> DynColors {
+        self.map_rgb_with(palette, |rgb| rgb.darken(amount))
+    }
+
+    /// Makes this color more colorful, when the ANSI colors are displayed using `palette`. See
+    /// [`Rgb::saturate`] and [`DynColors::lighten_with`].
+    #[must_use]
+    pub fn saturate_with(self, amount: f32, palette: &Palette) -> DynColors {
+        self.map_rgb_with(palette, |rgb| rgb.saturate(amount))
+    }
+
+    /// Makes this color less colorful, when the ANSI colors are displayed using `palette`. See
+    /// [`Rgb::desaturate`] and [`DynColors::lighten_with`].
+    #[must_use]
+    pub fn desaturate_with(self, amount: f32, palette: &Palette) -> DynColors {
+        self.map_rgb_with(palette, |rgb| rgb.desaturate(amount))
+    }
+
+    /// Mixes this color with `other`, when the ANSI colors are displayed using `palette`. See
+    /// [`Rgb::mix`] and [`DynColors::lighten_with`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette};
+    ///
+    /// // Halfway between the text and background colors
+    /// let palette = Palette::SOLARIZED_LIGHT;
+    /// let text = DynColors::Ansi(AnsiColors::Default);
+    /// let background = DynColors::Rgb(253, 246, 227);
+    /// assert_eq!(text.mix_with(background, 0.5, &palette), DynColors::Rgb(175, 183, 178));
+    /// ```
+    #[must_use]
+    pub fn mix_with(self, other: DynColors, t: f32, palette: &Palette) -> DynColors {
+        let other = palette.resolve_fg(other);
+        self.map_rgb_with(palette, |rgb| rgb.mix(other, t))
+    }
+
+    /// Returns the relative luminance of this color. See [`Rgb::relative_luminance`] and
+    /// [`DynColors::to_rgb`].
+    #[must_use]
+    pub fn relative_luminance(self) -> Option<f32> {
+        self.to_rgb().map(Rgb::relative_luminance)
+    }
+
+    /// Returns the contrast ratio between this color and `other`. See [`Rgb::contrast_ratio`] and
+    /// [`DynColors::to_rgb`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, CssColors, DynColors};
+    ///
+    /// let navy = DynColors::Css(CssColors::Navy);
+    /// assert!(navy.contrast_ratio(DynColors::Ansi(AnsiColors::White)).unwrap() > 7.0);
+    /// assert_eq!(navy.contrast_ratio(DynColors::Ansi(AnsiColors::Default)), None);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(self, other: DynColors) -> Option<f32> {
+        Some(self.to_rgb()?.contrast_ratio(other.to_rgb()?))
+    }
+
+    /// Returns the relative luminance of this color as a foreground, when the ANSI colors are
+    /// displayed using `palette`. See [`Rgb::relative_luminance`] and [`Palette::resolve_fg`].
+    #[must_use]
+    pub fn relative_luminance_with(self, palette: &Palette) -> f32 {
+        palette.resolve_fg(self).relative_luminance()
+    }
+
+    /// Returns the contrast ratio between this color as text and `background`, when the ANSI
+    /// colors are displayed using `palette`. See [`Rgb::contrast_ratio`].
+    ///
+    /// [`AnsiColors::Default`] is the palette's foreground color for this color, and its
+    /// background color for `background`.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette};
+    ///
+    /// let default = DynColors::Ansi(AnsiColors::Default);
+    /// let yellow = DynColors::Ansi(AnsiColors::Yellow);
+    /// assert!(yellow.contrast_ratio_with(default, &Palette::XTERM) < 2.0);
+    /// assert!(yellow.contrast_ratio_with(default, &Palette::DEFAULT) > 4.5);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio_with(self, background: DynColors, palette: &Palette) -> f32 {
+        palette
+            .resolve_fg(self)
+            .contrast_ratio(palette.resolve_bg(background))
+    }
+
+    /// Returns black or white, whichever is more readable on top of this color, or `None` for
+    /// [`AnsiColors::Default`]. See [`Rgb::readable_foreground`].
+    ///
+    /// The result uses the same kind of color as this one, so that it displays on terminals
+    /// which support this color: [`AnsiColors::Black`] or [`AnsiColors::BrightWhite`] for ANSI
+    /// colors, [`XtermColors::Black`] or [`XtermColors::White`] for Xterm colors, and RGB
+    /// otherwise.
+    ///
+    /// The ANSI colors are assumed to be displayed using [`Palette::DEFAULT`]; see
+    /// [`DynColors::readable_foreground_with`] for other palettes.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors};
+    ///
+    /// let yellow = DynColors::Ansi(AnsiColors::BrightYellow);
+    /// assert_eq!(yellow.readable_foreground(), Some(DynColors::Ansi(AnsiColors::Black)));
+    /// ```
+    #[must_use]
+    pub fn readable_foreground(self) -> Option<DynColors> {
+        match self {
+            DynColors::Ansi(AnsiColors::Default) => None,
+            _ => Some(self.readable_foreground_with(&Palette::DEFAULT)),
+        }
+    }
+
+    /// Returns black or white, whichever is more readable on top of this color when the ANSI
+    /// colors are displayed using `palette`. See [`DynColors::readable_foreground`].
+    ///
+    /// For ANSI colors, the palette's own black and bright white are compared, and
+    /// [`AnsiColors::Default`] is the palette's background color.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette};
+    ///
+    /// // Solarized's bright green is a dark gray
+    /// let background = DynColors::Ansi(AnsiColors::BrightGreen);
+    /// assert_eq!(
+    ///     background.readable_foreground(),
+    ///     Some(DynColors::Ansi(AnsiColors::Black))
+    /// );
+    /// assert_eq!(
+    ///     background.readable_foreground_with(&Palette::SOLARIZED_DARK),
+    ///     DynColors::Ansi(AnsiColors::BrightWhite)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn readable_foreground_with(self, palette: &Palette) -> DynColors {
+        let background = palette.resolve_bg(self);
+        match self {
+            DynColors::Ansi(_) => {
+                let black = palette.ansi_fg(AnsiColors::Black);
+                let white = palette.ansi_fg(AnsiColors::BrightWhite);
+                if background.contrast_ratio(black) >= background.contrast_ratio(white) {
+                    DynColors::Ansi(AnsiColors::Black)
+                } else {
+                    DynColors::Ansi(AnsiColors::BrightWhite)
+                }
+            }
+            // Xterm colors 16 and 231 are always black and white, unlike 0 and 15
+            DynColors::Xterm(_) if background.readable_foreground() == Rgb(0, 0, 0) => {
+                DynColors::Xterm(XtermColors::Black)
+            }
+            DynColors::Xterm(_) => DynColors::Xterm(XtermColors::White),
+            _ => {
+                let Rgb(r, g, b) = background.readable_foreground();
+                DynColors::Rgb(r, g, b)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CssColors;
+
+    #[test]
+    fn test_lightness() {
+        let (l, c, h) = Rgb(46, 49, 146).to_oklch();
+        let (lighter_l, lighter_c, lighter_h) = Rgb(46, 49, 146).lighten(0.2).to_oklch();
+        assert!((lighter_l - (l + 0.2)).abs() < 0.01);
+        assert!((lighter_h - h).abs() < 2.0);
+        assert!((lighter_c - c).abs() < 0.02);
+
+        assert_eq!(Rgb(10, 20, 30).lighten(0.0), Rgb(10, 20, 30));
+        assert_eq!(Rgb(10, 20, 30).darken(2.0), Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn test_saturation() {
+        let gray = Rgb(128, 128, 128);
+        assert_eq!(gray.saturate(1.0), gray);
+        assert_eq!(Rgb(0, 128, 0).desaturate(0.0), Rgb(0, 128, 0));
+        assert_eq!(
+            Rgb(0, 128, 0).saturate(-5.0),
+            Rgb(0, 128, 0).desaturate(1.0)
+        );
+
+        let (_, c, _) = Rgb(0, 128, 0).desaturate(0.5).to_oklch();
+        let (_, original, _) = Rgb(0, 128, 0).to_oklch();
+        assert!((c - original / 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_contrast() {
+        let white = Rgb(255, 255, 255);
+        assert_eq!(white.contrast_ratio(white), 1.0);
+        assert_eq!(Rgb(0, 0, 0).relative_luminance(), 0.0);
+        // Reference values from the WebAIM contrast checker
+        assert!((Rgb(0, 0, 255).contrast_ratio(white) - 8.59).abs() < 0.01);
+        assert!((Rgb(255, 165, 0).contrast_ratio(Rgb(0, 0, 0)) - 10.63).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_dyn_colors() {
+        let default = DynColors::Ansi(AnsiColors::Default);
+        let teal = DynColors::Css(CssColors::Teal);
+
+        assert_eq!(default.lighten(0.5), default);
+        assert_eq!(default.mix(teal, 0.5), default);
+        assert_eq!(teal.mix(default, 0.5), teal);
+        assert_eq!(teal.mix(teal, 0.5), DynColors::Rgb(0, 128, 128));
+        assert_eq!(default.relative_luminance(), None);
+        assert_eq!(teal.darken(1.0), DynColors::Rgb(0, 0, 0));
+        assert_eq!(default.readable_foreground(), None);
+    }
+
+    #[test]
+    fn test_dyn_colors_with_palette() {
+        let default = DynColors::Ansi(AnsiColors::Default);
+        let blue = DynColors::Ansi(AnsiColors::Blue);
+        let palette = Palette::CAMPBELL;
+
+        assert_eq!(blue.lighten_with(0.0, &palette), DynColors::Rgb(0, 55, 218));
+        assert_eq!(
+            blue.darken_with(0.1, &palette),
+            blue.map_rgb_with(&palette, |rgb| rgb.darken(0.1))
+        );
+        assert_eq!(
+            default.desaturate_with(0.0, &palette),
+            DynColors::Rgb(204, 204, 204)
+        );
+        assert_eq!(
+            blue.mix_with(default, 1.0, &palette),
+            DynColors::Rgb(204, 204, 204)
+        );
+        assert_eq!(
+            default.relative_luminance_with(&palette),
+            Rgb(204, 204, 204).relative_luminance()
+        );
+        assert_eq!(
+            default.contrast_ratio_with(default, &palette),
+            Rgb(204, 204, 204).contrast_ratio(Rgb(12, 12, 12))
+        );
+        assert_eq!(blue.contrast_ratio_with(blue, &Palette::VGA), 1.0);
+        assert_eq!(
+            DynColors::Css(CssColors::Teal).saturate_with(0.0, &palette),
+            DynColors::Rgb(0, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_readable_foreground() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let background = Rgb(r, g, b);
+                    let foreground = background.readable_foreground();
+                    assert!(background.contrast_ratio(foreground) >= 4.5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_readable_foreground_with() {
+        use crate::Style;
+
+        let default = DynColors::Ansi(AnsiColors::Default);
+        let ansi = |color| DynColors::Ansi(color);
+        assert_eq!(
+            default.readable_foreground_with(&Palette::SOLARIZED_DARK),
+            ansi(AnsiColors::BrightWhite)
+        );
+        assert_eq!(
+            default.readable_foreground_with(&Palette::XTERM),
+            ansi(AnsiColors::Black)
+        );
+        for palette in [
+            Palette::DEFAULT,
+            Palette::CAMPBELL,
+            Palette::SOLARIZED_LIGHT,
+        ] {
+            for color in [
+                AnsiColors::Yellow,
+                AnsiColors::Blue,
+                AnsiColors::White,
+                AnsiColors::BrightBlack,
+                AnsiColors::BrightGreen,
+                AnsiColors::Default,
+            ] {
+                let background = palette.ansi_bg(color);
+                let black = palette.ansi_fg(AnsiColors::Black);
+                let white = palette.ansi_fg(AnsiColors::BrightWhite);
+                let (chosen, other) = match ansi(color).readable_foreground_with(&palette) {
+                    DynColors::Ansi(AnsiColors::Black) => (black, white),
+                    _ => (white, black),
+                };
+                assert!(background.contrast_ratio(chosen) >= background.contrast_ratio(other));
+            }
+        }
+
+        // Xterm colors 0 to 15 come from the palette too
+        assert_eq!(
+            DynColors::Xterm(XtermColors::UserBrightGreen)
+                .readable_foreground_with(&Palette::SOLARIZED_DARK),
+            DynColors::Xterm(XtermColors::White)
+        );
+        assert_eq!(
+            DynColors::Rgb(0, 0, 0).readable_foreground_with(&Palette::SOLARIZED_LIGHT),
+            DynColors::Rgb(255, 255, 255)
+        );
+        let palette = Palette::SOLARIZED_DARK;
+        assert_eq!(
+            Style::new().on_color_auto_fg_with(AnsiColors::BrightGreen, &palette),
+            Style::new().on_bright_green().bright_white()
+        );
+    }
+
+    #[test]
+    fn test_readable_foreground_depth() {
+        use crate::{OwoColorize, Style};
+        use std::format;
+
+        let ansi = |color| Some(DynColors::Ansi(color));
+        assert_eq!(
+            DynColors::Ansi(AnsiColors::Yellow).readable_foreground(),
+            ansi(AnsiColors::Black)
+        );
+        assert_eq!(
+            DynColors::Ansi(AnsiColors::Blue).readable_foreground(),
+            ansi(AnsiColors::BrightWhite)
+        );
+
+        let xterm = |color| Some(DynColors::Xterm(color));
+        assert_eq!(
+            DynColors::Xterm(XtermColors::LaserLemon).readable_foreground(),
+            xterm(XtermColors::Black)
+        );
+        assert_eq!(
+            DynColors::Xterm(XtermColors::StratosBlue).readable_foreground(),
+            xterm(XtermColors::White)
+        );
+
+        assert_eq!(
+            DynColors::Css(CssColors::Navy).readable_foreground(),
+            Some(DynColors::Rgb(255, 255, 255))
+        );
+        assert_eq!(
+            DynColors::Rgb(255, 255, 0).readable_foreground(),
+            Some(DynColors::Rgb(0, 0, 0))
+        );
+
+        // No truecolor foreground next to a 16 color background
+        assert_eq!(
+            Style::new().on_color_auto_fg(AnsiColors::Yellow),
+            Style::new().on_yellow().black()
+        );
+        assert_eq!(
+            format!("{}", "x".on_color_auto_fg(AnsiColors::Yellow)),
+            "\x1b[30;43mx\x1b[0m"
+        );
+    }
+}
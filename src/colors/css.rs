@@ -20,10 +20,51 @@ macro_rules! css_color_types {
 
         use dynamic::CssColors;
 
+        impl CssColors {
+            /// All the CSS colors, in alphabetical order.
+            ///
+            /// ```rust
+            /// use owo_colors::CssColors;
+            ///
+            /// assert_eq!(CssColors::ALL.len(), 147);
+            /// assert_eq!(CssColors::ALL[0], CssColors::AliceBlue);
+            /// ```
+            pub const ALL: &'static [CssColors] = &[$(CssColors::$name,)*];
+
+            /// Returns the name of this color, such as `"AliceBlue"`.
+            ///
+            /// The name can be parsed back into the same color using [`str::parse`].
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        CssColors::$name => stringify!($name),
+                    )*
+                }
+            }
+
+            /// Returns the RGB value of this color.
+            ///
+            /// ```rust
+            /// use owo_colors::{CssColors, Rgb};
+            ///
+            /// assert_eq!(CssColors::Teal.rgb(), Rgb(0, 128, 128));
+            /// ```
+            #[must_use]
+            pub const fn rgb(self) -> crate::Rgb {
+                match self {
+                    $(
+                        CssColors::$name => crate::Rgb($r, $g, $b),
+                    )*
+                }
+            }
+        }
+
         impl crate::private::Sealed for CssColors {}
 
         impl crate::DynColor for CssColors {
             fn fmt_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_fg(f));
                 let color = match self {
                     $(
                         CssColors::$name => CustomColor::<$r, $g, $b>::ANSI_FG,
@@ -34,6 +75,7 @@ macro_rules! css_color_types {
             }
 
             fn fmt_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_bg(f));
                 let color = match self {
                     $(
                         CssColors::$name => CustomColor::<$r, $g, $b>::ANSI_BG,
@@ -44,6 +86,7 @@ macro_rules! css_color_types {
             }
 
             fn fmt_raw_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_fg(f));
                 let color = match self {
                     $(
                         CssColors::$name => CustomColor::<$r, $g, $b>::RAW_ANSI_FG,
@@ -54,6 +97,7 @@ macro_rules! css_color_types {
             }
 
             fn fmt_raw_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_bg(f));
                 let color = match self {
                     $(
                         CssColors::$name => CustomColor::<$r, $g, $b>::RAW_ANSI_BG,
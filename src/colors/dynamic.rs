@@ -9,25 +9,85 @@ use crate::OwoColorize;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Rgb(pub u8, pub u8, pub u8);
 
+/// Returns the value of a hex digit, panicking if it isn't one.
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("invalid hex digit in color"),
+    }
+}
+
+impl Rgb {
+    /// Creates a color from a `#rrggbb` or `#rgb` hex string, where the `#` is optional.
+    ///
+    /// This is meant for colors known at compile time, such as in a `const`, where an invalid
+    /// string is a compile error. To parse colors at runtime, use
+    /// [`DynColors::from_str`](crate::DynColors#impl-FromStr-for-DynColors) instead. See also
+    /// [`hex!`](crate::hex!).
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// const BLUE: Rgb = Rgb::from_hex("#2E3192");
+    /// assert_eq!(BLUE, Rgb(0x2E, 0x31, 0x92));
+    /// assert_eq!(Rgb::from_hex("fa0"), Rgb(0xff, 0xaa, 0x00));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const INVALID: owo_colors::Rgb = owo_colors::Rgb::from_hex("#2E319");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hex` isn't a valid hex color.
+    #[must_use]
+    pub const fn from_hex(hex: &str) -> Rgb {
+        let digits = match hex.as_bytes() {
+            [b'#', digits @ ..] => digits,
+            digits => digits,
+        };
+
+        match *digits {
+            [r, g, b] => Rgb(
+                hex_digit(r) * 0x11,
+                hex_digit(g) * 0x11,
+                hex_digit(b) * 0x11,
+            ),
+            [r1, r2, g1, g2, b1, b2] => Rgb(
+                hex_digit(r1) << 4 | hex_digit(r2),
+                hex_digit(g1) << 4 | hex_digit(g2),
+                hex_digit(b1) << 4 | hex_digit(b2),
+            ),
+            _ => panic!("hex color must have 3 or 6 digits"),
+        }
+    }
+}
+
 impl crate::private::Sealed for Rgb {}
 
 impl DynColor for Rgb {
     fn fmt_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_fg(f));
         let Rgb(r, g, b) = self;
         write!(f, "\x1b[38;2;{};{};{}m", r, g, b)
     }
 
     fn fmt_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_bg(f));
         let Rgb(r, g, b) = self;
         write!(f, "\x1b[48;2;{};{};{}m", r, g, b)
     }
 
     fn fmt_raw_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_fg(f));
         let Rgb(r, g, b) = self;
         write!(f, "38;2;{};{};{}", r, g, b)
     }
 
     fn fmt_raw_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_bg(f));
         let Rgb(r, g, b) = self;
         write!(f, "48;2;{};{};{}", r, g, b)
     }
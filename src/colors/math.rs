@@ -0,0 +1,256 @@
+//! Floating-point helpers for color space conversions.
+//!
+//! `core` doesn't provide transcendental functions such as `powf` or `cbrt` (they live in `std`),
+//! so minimal implementations that are accurate enough for 8-bit colors are provided here.
+
+use core::f32::consts::{FRAC_PI_2, LN_2, PI};
+
+/// Base-2 logarithm of a positive, finite number.
+fn log2(x: f32) -> f32 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    // Mantissa in [1, 2)
+    let mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), where the argument is in [0, 1/3)
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let series = z * (1.0 + z2 * (1.0 / 3.0 + z2 * (1.0 / 5.0 + z2 * (1.0 / 7.0 + z2 / 9.0))));
+
+    exponent as f32 + 2.0 * series / LN_2
+}
+
+/// Base-2 exponential of a finite number.
+fn exp2(x: f32) -> f32 {
+    if x < -126.0 {
+        return 0.0;
+    }
+    if x > 127.0 {
+        return f32::INFINITY;
+    }
+
+    let whole = floor(x);
+    let t = (x - whole) * LN_2;
+    // e^t for t in [0, ln(2))
+    let fraction = 1.0
+        + t * (1.0
+            + t * (1.0 / 2.0
+                + t * (1.0 / 6.0 + t * (1.0 / 24.0 + t * (1.0 / 120.0 + t * (1.0 / 720.0))))));
+
+    f32::from_bits(((whole as i32 + 127) as u32) << 23) * fraction
+}
+
+float_const_fn! {
+    /// Rounds towards negative infinity.
+    pub(crate) fn floor(x: f32) -> f32 {
+        let truncated = x as i32 as f32;
+        if truncated > x {
+            truncated - 1.0
+        } else {
+            truncated
+        }
+    }
+}
+
+float_const_fn! {
+    /// Absolute value.
+    pub(crate) fn abs(x: f32) -> f32 {
+        if x < 0.0 { -x } else { x }
+    }
+}
+
+float_const_fn! {
+    /// Clamps `x` to `[0, 1]`. NaN is left as-is.
+    pub(crate) fn clamp_unit(x: f32) -> f32 {
+        if x < 0.0 {
+            0.0
+        } else if x > 1.0 {
+            1.0
+        } else {
+            x
+        }
+    }
+}
+
+float_const_fn! {
+    /// Wraps an angle in degrees to `[0, 360)`.
+    pub(crate) fn wrap_degrees(degrees: f32) -> f32 {
+        let wrapped = degrees - 360.0 * floor(degrees / 360.0);
+        // Rounding can land exactly on 360 for tiny negative angles
+        if wrapped >= 360.0 { 0.0 } else { wrapped }
+    }
+}
+
+float_const_fn! {
+    /// Converts a channel in `[0, 1]` to an 8-bit value, rounding to the nearest integer and
+    /// clamping out-of-range values.
+    pub(crate) fn to_u8(x: f32) -> u8 {
+        // `as` saturates, and maps NaN to 0.
+        (x * 255.0 + 0.5) as u8
+    }
+}
+
+/// `x` raised to the power `y`, for non-negative `x`.
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    exp2(y * log2(x))
+}
+
+/// Cube root.
+pub(crate) fn cbrt(x: f32) -> f32 {
+    if x == 0.0 {
+        return 0.0;
+    }
+    let magnitude = abs(x);
+    let mut root = exp2(log2(magnitude) / 3.0);
+    // One Newton-Raphson step to clean up the approximation error
+    root -= (root * root * root - magnitude) / (3.0 * root * root);
+
+    if x < 0.0 { -root } else { root }
+}
+
+/// Square root of a non-negative number.
+pub(crate) fn sqrt(x: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let root = exp2(log2(x) / 2.0);
+    // One Newton-Raphson step to clean up the approximation error
+    (root + x / root) / 2.0
+}
+
+/// Sine of an angle in radians.
+pub(crate) fn sin(x: f32) -> f32 {
+    // Reduce to [-pi, pi], then to [-pi/2, pi/2] using sin(x) = sin(pi - x)
+    let mut x = x - 2.0 * PI * floor((x + PI) / (2.0 * PI));
+    if x > FRAC_PI_2 {
+        x = PI - x;
+    } else if x < -FRAC_PI_2 {
+        x = -PI - x;
+    }
+
+    let x2 = x * x;
+    x * (1.0
+        - x2 / 6.0 * (1.0 - x2 / 20.0 * (1.0 - x2 / 42.0 * (1.0 - x2 / 72.0 * (1.0 - x2 / 110.0)))))
+}
+
+/// Cosine of an angle in radians.
+pub(crate) fn cos(x: f32) -> f32 {
+    sin(x + FRAC_PI_2)
+}
+
+/// Arctangent of `x` for `|x| <= 1`.
+fn atan_unit(x: f32) -> f32 {
+    // atan(x) = 2 * atan(x / (1 + sqrt(1 + x^2))), which brings the argument below tan(pi/8)
+    let z = x / (1.0 + sqrt(1.0 + x * x));
+    let z2 = z * z;
+    let series = z
+        * (1.0
+            - z2 * (1.0 / 3.0
+                - z2 * (1.0 / 5.0
+                    - z2 * (1.0 / 7.0 - z2 * (1.0 / 9.0 - z2 * (1.0 / 11.0 - z2 / 13.0))))));
+    2.0 * series
+}
+
+/// The angle in radians, in `[-pi, pi]`, of the point `(x, y)`.
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    if x == 0.0 && y == 0.0 {
+        0.0
+    } else if abs(x) >= abs(y) {
+        let angle = atan_unit(y / x);
+        if x > 0.0 {
+            angle
+        } else if y >= 0.0 {
+            angle + PI
+        } else {
+            angle - PI
+        }
+    } else if y > 0.0 {
+        FRAC_PI_2 - atan_unit(x / y)
+    } else {
+        -FRAC_PI_2 - atan_unit(x / y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-5 + 1e-6,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_powf() {
+        for &(x, y) in &[
+            (0.5, 2.4),
+            (0.04, 2.4),
+            (0.9, 1.0 / 2.4),
+            (2.0, 10.0),
+            (1e-4, 0.5),
+        ] {
+            assert_close(powf(x, y), f32::powf(x, y));
+        }
+    }
+
+    #[test]
+    fn test_roots() {
+        for &x in &[1e-6, 0.001, 0.2, 1.0, 27.0, -8.0] {
+            assert_close(cbrt(x), f32::cbrt(x));
+        }
+        for &x in &[1e-6, 0.001, 0.2, 1.0, 2.0, 1e4] {
+            assert_close(sqrt(x), f32::sqrt(x));
+        }
+    }
+
+    #[test]
+    fn test_trig() {
+        for i in -40..=40 {
+            let x = i as f32 * 0.2;
+            assert!((sin(x) - x.sin()).abs() < 1e-6, "sin({})", x);
+            assert!((cos(x) - x.cos()).abs() < 1e-6, "cos({})", x);
+        }
+
+        for &(y, x) in &[
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 0.0),
+            (2.0, -1.0),
+            (-0.5, -3.0),
+            (-3.0, -0.5),
+            (-1.0, 0.2),
+            (0.0, -1.0),
+        ] {
+            assert!(
+                (atan2(y, x) - f32::atan2(y, x)).abs() < 1e-6,
+                "atan2({}, {})",
+                y,
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_u8() {
+        assert_eq!(to_u8(0.0), 0);
+        assert_eq!(to_u8(1.0), 255);
+        assert_eq!(to_u8(0.5), 128);
+        assert_eq!(to_u8(-0.2), 0);
+        assert_eq!(to_u8(1.3), 255);
+    }
+
+    #[test]
+    fn test_wrap_degrees() {
+        assert_eq!(wrap_degrees(0.0), 0.0);
+        assert_eq!(wrap_degrees(360.0), 0.0);
+        assert_eq!(wrap_degrees(-90.0), 270.0);
+        assert_eq!(wrap_degrees(725.0), 5.0);
+    }
+}
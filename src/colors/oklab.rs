@@ -0,0 +1,182 @@
+//! Conversions between sRGB and the [OKLab](https://bottosson.github.io/posts/oklab/) perceptual
+//! color space.
+use super::math::{atan2, cbrt, clamp_unit, cos, powf, sin, sqrt, to_u8, wrap_degrees};
+use crate::Rgb;
+
+/// A color in the OKLab color space, where euclidean distance approximates perceived difference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Oklab {
+    pub(crate) l: f32,
+    pub(crate) a: f32,
+    pub(crate) b: f32,
+}
+
+/// Converts a gamma-encoded sRGB channel to linear light.
+pub(crate) fn srgb_to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear light channel to a gamma-encoded sRGB channel.
+pub(crate) fn linear_to_srgb(channel: f32) -> u8 {
+    let c = if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * powf(channel, 1.0 / 2.4) - 0.055
+    };
+    to_u8(c)
+}
+
+/// Returns true if every linear channel is within the sRGB gamut, allowing for rounding errors.
+fn in_gamut(linear: [f32; 3]) -> bool {
+    const EPSILON: f32 = 1e-4;
+    linear
+        .iter()
+        .all(|&channel| (-EPSILON..=1.0 + EPSILON).contains(&channel))
+}
+
+impl Oklab {
+    pub(crate) fn from_rgb(Rgb(r, g, b): Rgb) -> Self {
+        let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+        let l = cbrt(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    /// Creates a color from its lightness, chroma and hue (in degrees), i.e. from OKLCH.
+    pub(crate) fn from_lch(l: f32, c: f32, h: f32) -> Self {
+        let radians = wrap_degrees(h).to_radians();
+        Self {
+            l,
+            a: c * cos(radians),
+            b: c * sin(radians),
+        }
+    }
+
+    /// Returns the lightness, chroma and hue (in degrees, in `[0, 360)`) of this color.
+    pub(crate) fn to_lch(self) -> (f32, f32, f32) {
+        let c = sqrt(self.a * self.a + self.b * self.b);
+        let h = wrap_degrees(atan2(self.b, self.a).to_degrees());
+        (self.l, c, h)
+    }
+
+    fn to_linear_rgb(self) -> [f32; 3] {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Converts back to sRGB.
+    ///
+    /// Colors outside of the sRGB gamut have their chroma reduced (keeping their lightness and hue)
+    /// until they fit.
+    pub(crate) fn to_rgb(self) -> Rgb {
+        let l = clamp_unit(self.l);
+        let mut linear = Self { l, ..self }.to_linear_rgb();
+
+        if !in_gamut(linear) {
+            // Binary search for the largest fraction of the chroma that is in gamut
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..16 {
+                let scale = (low + high) / 2.0;
+                let candidate = Self {
+                    l,
+                    a: self.a * scale,
+                    b: self.b * scale,
+                }
+                .to_linear_rgb();
+                if in_gamut(candidate) {
+                    low = scale;
+                    linear = candidate;
+                } else {
+                    high = scale;
+                }
+            }
+            if low == 0.0 {
+                linear = Self { l, a: 0.0, b: 0.0 }.to_linear_rgb();
+            }
+        }
+
+        Rgb(
+            linear_to_srgb(linear[0]),
+            linear_to_srgb(linear[1]),
+            linear_to_srgb(linear[2]),
+        )
+    }
+
+    /// Linearly interpolates between two colors, where `t = 0` is `self` and `t = 1` is `other`.
+    pub(crate) fn mix(self, other: Self, t: f32) -> Self {
+        Self {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// The squared euclidean distance between two colors (ΔE<sub>OK</sub>²).
+    pub(crate) fn distance_squared(self, other: Self) -> f32 {
+        let (dl, da, db) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        dl * dl + da * da + db * db
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for &rgb in &[
+            Rgb(0, 0, 0),
+            Rgb(255, 255, 255),
+            Rgb(255, 0, 0),
+            Rgb(46, 49, 146),
+            Rgb(1, 2, 3),
+            Rgb(250, 128, 114),
+        ] {
+            assert_eq!(Oklab::from_rgb(rgb).to_rgb(), rgb);
+
+            let (l, c, h) = Oklab::from_rgb(rgb).to_lch();
+            assert_eq!(Oklab::from_lch(l, c, h).to_rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn test_gamut_mapping() {
+        // Far more saturated than sRGB can display, so the chroma is reduced
+        let rgb = Oklab::from_lch(0.7, 0.5, 150.0).to_rgb();
+        let (l, _, h) = Oklab::from_rgb(rgb).to_lch();
+        assert!((l - 0.7).abs() < 0.01);
+        assert!((h - 150.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_from_rgb() {
+        let white = Oklab::from_rgb(Rgb(255, 255, 255));
+        assert!((white.l - 1.0).abs() < 1e-3);
+        assert!(white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+
+        let red = Oklab::from_rgb(Rgb(255, 0, 0));
+        assert!((red.l - 0.628).abs() < 1e-3);
+        assert!((red.a - 0.2249).abs() < 1e-3);
+        assert!((red.b - 0.1258).abs() < 1e-3);
+    }
+}
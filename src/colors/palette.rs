@@ -0,0 +1,281 @@
+//! The RGB values terminals display the ANSI colors as.
+use crate::{AnsiColors, DynColors, Rgb};
+
+/// The RGB values a terminal displays the 16 [`AnsiColors`] as, along with its default foreground
+/// and background colors.
+///
+/// ANSI colors don't have fixed RGB values, so a palette is needed whenever they have to be
+/// compared with other colors. Functions which don't take a palette, such as
+/// [`DynColors::to_rgb`] and [`Rgb::to_ansi`], use [`Palette::DEFAULT`]; the `*_with` variants
+/// of those functions take the palette to use instead. With the `supports-colors` feature,
+/// `with_palette` sets the palette `if_supports_color` uses when it downgrades colors.
+///
+/// ```rust
+/// use owo_colors::{AnsiColors, DynColors, Palette, Rgb};
+///
+/// let red = DynColors::Ansi(AnsiColors::Red);
+/// assert_eq!(red.to_rgb_with(&Palette::CAMPBELL), Some(Rgb(197, 15, 31)));
+/// assert_eq!(Palette::SOLARIZED_DARK.ansi_bg(AnsiColors::Default), Rgb(0, 43, 54));
+/// assert_eq!(Rgb(200, 20, 40).to_ansi_with(&Palette::CAMPBELL), AnsiColors::Red);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palette {
+    /// The colors, in the order of their SGR codes: black, red, green, yellow, blue, magenta,
+    /// cyan and white, followed by their bright variants.
+    pub colors: [Rgb; 16],
+    /// The color text is displayed in by default ([`AnsiColors::Default`] as a foreground)
+    pub foreground: Rgb,
+    /// The color behind text by default ([`AnsiColors::Default`] as a background)
+    pub background: Rgb,
+}
+
+/// Returns the position of an ANSI color in [`Palette::colors`], or `None` for
+/// [`AnsiColors::Default`].
+pub(crate) const fn ansi_index(color: AnsiColors) -> Option<usize> {
+    Some(match color {
+        AnsiColors::Black => 0,
+        AnsiColors::Red => 1,
+        AnsiColors::Green => 2,
+        AnsiColors::Yellow => 3,
+        AnsiColors::Blue => 4,
+        AnsiColors::Magenta => 5,
+        AnsiColors::Cyan => 6,
+        AnsiColors::White => 7,
+        AnsiColors::BrightBlack => 8,
+        AnsiColors::BrightRed => 9,
+        AnsiColors::BrightGreen => 10,
+        AnsiColors::BrightYellow => 11,
+        AnsiColors::BrightBlue => 12,
+        AnsiColors::BrightMagenta => 13,
+        AnsiColors::BrightCyan => 14,
+        AnsiColors::BrightWhite => 15,
+        AnsiColors::Default => return None,
+    })
+}
+
+/// The [Solarized](https://ethanschoonover.com/solarized/) colors, as mapped by its terminal
+/// themes.
+const SOLARIZED: [Rgb; 16] = [
+    Rgb(7, 54, 66),
+    Rgb(220, 50, 47),
+    Rgb(133, 153, 0),
+    Rgb(181, 137, 0),
+    Rgb(38, 139, 210),
+    Rgb(211, 54, 130),
+    Rgb(42, 161, 152),
+    Rgb(238, 232, 213),
+    Rgb(0, 43, 54),
+    Rgb(203, 75, 22),
+    Rgb(88, 110, 117),
+    Rgb(101, 123, 131),
+    Rgb(131, 148, 150),
+    Rgb(108, 113, 196),
+    Rgb(147, 161, 161),
+    Rgb(253, 246, 227),
+];
+
+impl Palette {
+    /// The RGB values of [`XtermColors`](crate::XtermColors) 0 to 15, which are used when no
+    /// palette is given.
+    pub const DEFAULT: Palette = Palette {
+        colors: [
+            Rgb(0, 0, 0),
+            Rgb(128, 0, 0),
+            Rgb(0, 128, 0),
+            Rgb(128, 128, 0),
+            Rgb(0, 0, 128),
+            Rgb(128, 0, 128),
+            Rgb(0, 128, 128),
+            Rgb(192, 192, 192),
+            Rgb(128, 128, 128),
+            Rgb(255, 0, 0),
+            Rgb(0, 255, 0),
+            Rgb(255, 255, 0),
+            Rgb(0, 0, 255),
+            Rgb(255, 0, 255),
+            Rgb(0, 255, 255),
+            Rgb(255, 255, 255),
+        ],
+        foreground: Rgb(192, 192, 192),
+        background: Rgb(0, 0, 0),
+    };
+
+    /// The default colors of xterm.
+    pub const XTERM: Palette = Palette {
+        colors: [
+            Rgb(0, 0, 0),
+            Rgb(205, 0, 0),
+            Rgb(0, 205, 0),
+            Rgb(205, 205, 0),
+            Rgb(0, 0, 238),
+            Rgb(205, 0, 205),
+            Rgb(0, 205, 205),
+            Rgb(229, 229, 229),
+            Rgb(127, 127, 127),
+            Rgb(255, 0, 0),
+            Rgb(0, 255, 0),
+            Rgb(255, 255, 0),
+            Rgb(92, 92, 255),
+            Rgb(255, 0, 255),
+            Rgb(0, 255, 255),
+            Rgb(255, 255, 255),
+        ],
+        foreground: Rgb(0, 0, 0),
+        background: Rgb(255, 255, 255),
+    };
+
+    /// The colors of VGA text mode, also used by the Linux console.
+    pub const VGA: Palette = Palette {
+        colors: [
+            Rgb(0, 0, 0),
+            Rgb(170, 0, 0),
+            Rgb(0, 170, 0),
+            Rgb(170, 85, 0),
+            Rgb(0, 0, 170),
+            Rgb(170, 0, 170),
+            Rgb(0, 170, 170),
+            Rgb(170, 170, 170),
+            Rgb(85, 85, 85),
+            Rgb(255, 85, 85),
+            Rgb(85, 255, 85),
+            Rgb(255, 255, 85),
+            Rgb(85, 85, 255),
+            Rgb(255, 85, 255),
+            Rgb(85, 255, 255),
+            Rgb(255, 255, 255),
+        ],
+        foreground: Rgb(170, 170, 170),
+        background: Rgb(0, 0, 0),
+    };
+
+    /// Campbell, the default color scheme of Windows Terminal and of the Windows console since
+    /// Windows 10.
+    pub const CAMPBELL: Palette = Palette {
+        colors: [
+            Rgb(12, 12, 12),
+            Rgb(197, 15, 31),
+            Rgb(19, 161, 14),
+            Rgb(193, 156, 0),
+            Rgb(0, 55, 218),
+            Rgb(136, 23, 152),
+            Rgb(58, 150, 221),
+            Rgb(204, 204, 204),
+            Rgb(118, 118, 118),
+            Rgb(231, 72, 86),
+            Rgb(22, 198, 12),
+            Rgb(249, 241, 165),
+            Rgb(59, 120, 255),
+            Rgb(180, 0, 158),
+            Rgb(97, 214, 214),
+            Rgb(242, 242, 242),
+        ],
+        foreground: Rgb(204, 204, 204),
+        background: Rgb(12, 12, 12),
+    };
+
+    /// The dark variant of [Solarized](https://ethanschoonover.com/solarized/).
+    pub const SOLARIZED_DARK: Palette = Palette {
+        colors: SOLARIZED,
+        foreground: Rgb(131, 148, 150),
+        background: Rgb(0, 43, 54),
+    };
+
+    /// The light variant of [Solarized](https://ethanschoonover.com/solarized/).
+    pub const SOLARIZED_LIGHT: Palette = Palette {
+        colors: SOLARIZED,
+        foreground: Rgb(101, 123, 131),
+        background: Rgb(253, 246, 227),
+    };
+
+    /// Returns the RGB value of an ANSI color used as a foreground, where
+    /// [`AnsiColors::Default`] is the palette's foreground color.
+    #[must_use]
+    pub const fn ansi_fg(&self, color: AnsiColors) -> Rgb {
+        match ansi_index(color) {
+            Some(index) => self.colors[index],
+            None => self.foreground,
+        }
+    }
+
+    /// Returns the RGB value of an ANSI color used as a background, where
+    /// [`AnsiColors::Default`] is the palette's background color.
+    #[must_use]
+    pub const fn ansi_bg(&self, color: AnsiColors) -> Rgb {
+        match ansi_index(color) {
+            Some(index) => self.colors[index],
+            None => self.background,
+        }
+    }
+
+    /// Returns the RGB value of a color used as a foreground. See [`Palette::ansi_fg`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette, Rgb};
+    ///
+    /// let palette = Palette::VGA;
+    /// let text = palette.resolve_fg(DynColors::Ansi(AnsiColors::Default));
+    /// let background = palette.resolve_bg(DynColors::Ansi(AnsiColors::Blue));
+    /// assert_eq!(text, Rgb(170, 170, 170));
+    /// assert!(text.contrast_ratio(background) > 4.5);
+    /// ```
+    #[must_use]
+    pub fn resolve_fg(&self, color: DynColors) -> Rgb {
+        match color {
+            DynColors::Ansi(ansi) => self.ansi_fg(ansi),
+            _ => self.resolve_bg(color),
+        }
+    }
+
+    /// Returns the RGB value of a color used as a background. See [`Palette::ansi_bg`].
+    #[must_use]
+    pub fn resolve_bg(&self, color: DynColors) -> Rgb {
+        match color {
+            DynColors::Ansi(ansi) => self.ansi_bg(ansi),
+            DynColors::Css(css) => css.rgb(),
+            DynColors::Xterm(xterm) => match u8::from(xterm) {
+                index @ 0..=15 => self.colors[index as usize],
+                _ => xterm.rgb(),
+            },
+            DynColors::Rgb(r, g, b) => Rgb(r, g, b),
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::XtermColors;
+
+    #[test]
+    fn test_default_matches_xterm_colors() {
+        for (index, &rgb) in Palette::DEFAULT.colors.iter().enumerate() {
+            assert_eq!(XtermColors::from(index as u8).rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let palette = Palette::VGA;
+        let default = DynColors::Ansi(AnsiColors::Default);
+        assert_eq!(palette.resolve_fg(default), Rgb(170, 170, 170));
+        assert_eq!(palette.resolve_bg(default), Rgb(0, 0, 0));
+        assert_eq!(
+            palette.resolve_fg(DynColors::Ansi(AnsiColors::Yellow)),
+            Rgb(170, 85, 0)
+        );
+        assert_eq!(
+            palette.resolve_fg(DynColors::Xterm(XtermColors::UserYellow)),
+            Rgb(170, 85, 0)
+        );
+        assert_eq!(
+            palette.resolve_bg(DynColors::Xterm(XtermColors::FlushOrange)),
+            Rgb(255, 135, 0)
+        );
+    }
+}
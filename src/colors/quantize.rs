@@ -0,0 +1,289 @@
+//! Perceptual nearest-color matching, used to convert colors to the smaller palettes supported by
+//! some terminals.
+use super::oklab::Oklab;
+use crate::{AnsiColors, CssColors, DynColors, Palette, Rgb, XtermColors};
+
+/// The number of colors a terminal can display.
+///
+/// Used with [`DynColors::downgrade`] to convert a color to one that can be displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// The 16 basic ANSI colors.
+    Ansi16,
+    /// The 256 xterm colors.
+    Xterm256,
+    /// 24-bit RGB colors ("truecolor").
+    TrueColor,
+}
+
+/// The intensity of each step of the 6x6x6 xterm color cube (indices 16-231).
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The ANSI colors in the same order as xterm indices 0-15.
+const ANSI_BY_INDEX: [AnsiColors; 16] = [
+    AnsiColors::Black,
+    AnsiColors::Red,
+    AnsiColors::Green,
+    AnsiColors::Yellow,
+    AnsiColors::Blue,
+    AnsiColors::Magenta,
+    AnsiColors::Cyan,
+    AnsiColors::White,
+    AnsiColors::BrightBlack,
+    AnsiColors::BrightRed,
+    AnsiColors::BrightGreen,
+    AnsiColors::BrightYellow,
+    AnsiColors::BrightBlue,
+    AnsiColors::BrightMagenta,
+    AnsiColors::BrightCyan,
+    AnsiColors::BrightWhite,
+];
+
+/// Returns the index of the cube step closest to `value`.
+fn nearest_cube_step(value: u8) -> usize {
+    match value {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => ((value as usize - 35) / 40).min(5),
+    }
+}
+
+/// Keeps track of the closest candidate seen so far.
+struct Nearest<T> {
+    target: Oklab,
+    best: Option<(f32, T)>,
+}
+
+impl<T> Nearest<T> {
+    fn new(target: Rgb) -> Self {
+        Self {
+            target: Oklab::from_rgb(target),
+            best: None,
+        }
+    }
+
+    fn consider(&mut self, candidate: Rgb, value: T) {
+        let distance = self.target.distance_squared(Oklab::from_rgb(candidate));
+        if self
+            .best
+            .as_ref()
+            .map_or(true, |(best, _)| distance < *best)
+        {
+            self.best = Some((distance, value));
+        }
+    }
+
+    fn into_inner(self) -> T {
+        self.best.expect("at least one candidate is considered").1
+    }
+}
+
+impl Rgb {
+    /// Returns the xterm color that looks the closest to this color.
+    ///
+    /// Colors are compared by their distance in the [OKLab](https://bottosson.github.io/posts/oklab/)
+    /// color space, which approximates how different they look. Only the color cube and the
+    /// grayscale ramp (indices 16-255) are considered, since terminals commonly customize the
+    /// first 16 colors.
+    ///
+    /// ```rust
+    /// use owo_colors::{Rgb, XtermColors};
+    ///
+    /// assert_eq!(Rgb(250, 130, 5).to_xterm(), XtermColors::FlushOrange);
+    /// assert_eq!(Rgb(128, 128, 128).to_xterm(), XtermColors::Gray);
+    /// ```
+    #[must_use]
+    pub fn to_xterm(self) -> XtermColors {
+        let mut nearest = Nearest::new(self);
+
+        // The perceptually closest cube color is always next to the closest one per channel.
+        let steps = [
+            nearest_cube_step(self.0),
+            nearest_cube_step(self.1),
+            nearest_cube_step(self.2),
+        ];
+        let neighbors = |step: usize| step.saturating_sub(1)..=(step + 1).min(5);
+        for r in neighbors(steps[0]) {
+            for g in neighbors(steps[1]) {
+                for b in neighbors(steps[2]) {
+                    let candidate = Rgb(CUBE_STEPS[r], CUBE_STEPS[g], CUBE_STEPS[b]);
+                    nearest.consider(candidate, 16 + 36 * r + 6 * g + b);
+                }
+            }
+        }
+
+        // The grayscale ramp goes from 8 to 238 in steps of 10.
+        for step in 0..24 {
+            let value = 8 + 10 * step as u8;
+            nearest.consider(Rgb(value, value, value), 232 + step);
+        }
+
+        XtermColors::from(nearest.into_inner() as u8)
+    }
+
+    /// Returns the basic ANSI color that looks the closest to this color.
+    ///
+    /// The ANSI colors don't have fixed RGB values, so [`Palette::DEFAULT`] is assumed. Colors are
+    /// compared by their distance in the [OKLab](https://bottosson.github.io/posts/oklab/) color
+    /// space.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, Rgb};
+    ///
+    /// assert_eq!(Rgb(250, 10, 10).to_ansi(), AnsiColors::BrightRed);
+    /// assert_eq!(Rgb(200, 200, 200).to_ansi(), AnsiColors::White);
+    /// ```
+    #[must_use]
+    pub fn to_ansi(self) -> AnsiColors {
+        self.to_ansi_with(&Palette::DEFAULT)
+    }
+
+    /// Returns the basic ANSI color that looks the closest to this color, when the ANSI colors are
+    /// displayed using `palette`. See [`Rgb::to_ansi`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, Palette, Rgb};
+    ///
+    /// let red = Rgb(205, 0, 0);
+    /// assert_eq!(red.to_ansi(), AnsiColors::BrightRed);
+    /// assert_eq!(red.to_ansi_with(&Palette::XTERM), AnsiColors::Red);
+    /// ```
+    #[must_use]
+    pub fn to_ansi_with(self, palette: &Palette) -> AnsiColors {
+        let mut nearest = Nearest::new(self);
+        for (&rgb, &ansi) in palette.colors.iter().zip(&ANSI_BY_INDEX) {
+            nearest.consider(rgb, ansi);
+        }
+        nearest.into_inner()
+    }
+}
+
+impl XtermColors {
+    /// Returns the basic ANSI color that looks the closest to this color.
+    ///
+    /// The first 16 xterm colors map directly onto the ANSI colors, the others are matched as
+    /// described in [`Rgb::to_ansi`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, XtermColors};
+    ///
+    /// assert_eq!(XtermColors::UserBlue.to_ansi(), AnsiColors::Blue);
+    /// assert_eq!(XtermColors::Red.to_ansi(), AnsiColors::BrightRed);
+    /// ```
+    #[must_use]
+    pub fn to_ansi(self) -> AnsiColors {
+        self.to_ansi_with(&Palette::DEFAULT)
+    }
+
+    /// Returns the basic ANSI color that looks the closest to this color, when the ANSI colors are
+    /// displayed using `palette`. See [`XtermColors::to_ansi`].
+    #[must_use]
+    pub fn to_ansi_with(self, palette: &Palette) -> AnsiColors {
+        match u8::from(self) {
+            index @ 0..=15 => ANSI_BY_INDEX[index as usize],
+            _ => self.rgb().to_ansi_with(palette),
+        }
+    }
+}
+
+impl From<XtermColors> for Rgb {
+    fn from(color: XtermColors) -> Self {
+        color.rgb()
+    }
+}
+
+impl From<CssColors> for Rgb {
+    fn from(color: CssColors) -> Self {
+        color.rgb()
+    }
+}
+
+impl DynColors {
+    /// Converts this color to the closest one that can be displayed with the given color depth.
+    ///
+    /// ANSI colors are left as-is, since every color depth supports them. Other colors are matched
+    /// using [`Rgb::to_xterm`] and [`Rgb::to_ansi`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, ColorDepth, DynColors, XtermColors};
+    ///
+    /// let orange = DynColors::Rgb(255, 135, 0);
+    /// assert_eq!(
+    ///     orange.downgrade(ColorDepth::Xterm256),
+    ///     DynColors::Xterm(XtermColors::FlushOrange)
+    /// );
+    /// assert_eq!(
+    ///     orange.downgrade(ColorDepth::Ansi16),
+    ///     DynColors::Ansi(AnsiColors::BrightRed)
+    /// );
+    /// assert_eq!(orange.downgrade(ColorDepth::TrueColor), orange);
+    /// ```
+    #[must_use]
+    pub fn downgrade(self, depth: ColorDepth) -> DynColors {
+        self.downgrade_with(depth, &Palette::DEFAULT)
+    }
+
+    /// Converts this color to the closest one that can be displayed with the given color depth,
+    /// when the ANSI colors are displayed using `palette`. See [`DynColors::downgrade`].
+    #[must_use]
+    pub fn downgrade_with(self, depth: ColorDepth, palette: &Palette) -> DynColors {
+        match (depth, self) {
+            (ColorDepth::TrueColor, _) | (_, DynColors::Ansi(_)) => self,
+            (ColorDepth::Xterm256, DynColors::Xterm(_)) => self,
+            (ColorDepth::Xterm256, DynColors::Css(css)) => DynColors::Xterm(css.rgb().to_xterm()),
+            (ColorDepth::Xterm256, DynColors::Rgb(r, g, b)) => {
+                DynColors::Xterm(Rgb(r, g, b).to_xterm())
+            }
+            (ColorDepth::Ansi16, DynColors::Xterm(xterm)) => {
+                DynColors::Ansi(xterm.to_ansi_with(palette))
+            }
+            (ColorDepth::Ansi16, DynColors::Css(css)) => {
+                DynColors::Ansi(css.rgb().to_ansi_with(palette))
+            }
+            (ColorDepth::Ansi16, DynColors::Rgb(r, g, b)) => {
+                DynColors::Ansi(Rgb(r, g, b).to_ansi_with(palette))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_xterm() {
+        assert_eq!(Rgb(255, 0, 0).to_xterm(), XtermColors::Red);
+        assert_eq!(Rgb(255, 135, 0).to_xterm(), XtermColors::FlushOrange);
+        assert_eq!(Rgb(0, 0, 0).to_xterm(), XtermColors::Black);
+        assert_eq!(Rgb(255, 255, 255).to_xterm(), XtermColors::White);
+        assert_eq!(Rgb(30, 30, 30).to_xterm(), XtermColors::LightCodGray);
+
+        // Every color in the cube and the grayscale ramp maps back to itself
+        for index in 16..=255 {
+            let xterm = XtermColors::from(index);
+            assert_eq!(xterm.rgb().to_xterm(), xterm);
+        }
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(Rgb(120, 0, 0).to_ansi(), AnsiColors::Red);
+        assert_eq!(Rgb(10, 10, 10).to_ansi(), AnsiColors::Black);
+        assert_eq!(Rgb(0, 0, 240).to_ansi(), AnsiColors::BrightBlue);
+        assert_eq!(Rgb(255, 255, 80).to_ansi(), AnsiColors::BrightYellow);
+        assert_eq!(Rgb(0, 128, 128).to_ansi(), AnsiColors::Cyan);
+    }
+
+    #[test]
+    fn test_downgrade_css() {
+        assert_eq!(
+            DynColors::Css(CssColors::Orange).downgrade(ColorDepth::Xterm256),
+            DynColors::Xterm(XtermColors::YellowSea)
+        );
+        assert_eq!(
+            DynColors::Css(CssColors::Navy).downgrade(ColorDepth::Ansi16),
+            DynColors::Ansi(AnsiColors::Blue)
+        );
+    }
+}
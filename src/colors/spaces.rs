@@ -0,0 +1,328 @@
+//! Conversions between RGB and the HSL, HSV and OKLCH color representations.
+use super::math::{abs, clamp_unit, floor, to_u8, wrap_degrees};
+use super::oklab::Oklab;
+use crate::{AnsiColors, DynColors, Palette, Rgb};
+
+float_const_fn! {
+    /// Builds an RGB color from a hue sector in `[0, 6)`, a chroma and the amount to add to every
+    /// channel.
+    fn from_hue_chroma(sector: f32, chroma: f32, offset: f32) -> Rgb {
+        let x = chroma * (1.0 - abs(sector - 2.0 * floor(sector / 2.0) - 1.0));
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Rgb(to_u8(r + offset), to_u8(g + offset), to_u8(b + offset))
+    }
+}
+
+float_const_fn! {
+    /// Returns the hue in degrees, the largest channel and the difference between the largest and
+    /// smallest channels, with channels in `[0, 1]`.
+    fn hue_max_delta(rgb: Rgb) -> (f32, f32, f32) {
+        let Rgb(r, g, b) = rgb;
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+
+        let max = if r > g { if r > b { r } else { b } } else if g > b { g } else { b };
+        let min = if r < g { if r < b { r } else { b } } else if g < b { g } else { b };
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            wrap_degrees(60.0 * (g - b) / delta)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, max, delta)
+    }
+}
+
+impl Rgb {
+    float_const_fn! {
+        /// Creates a color from its hue (in degrees), saturation and lightness. Saturation and
+        /// lightness range from 0 to 1.
+        ///
+        /// *This method is const on Rust 1.82+.*
+        ///
+        /// ```rust
+        /// use owo_colors::Rgb;
+        ///
+        /// assert_eq!(Rgb::from_hsl(210.0, 0.5, 0.4), Rgb(51, 102, 153));
+        /// ```
+        #[must_use]
+        pub fn from_hsl(h: f32, s: f32, l: f32) -> Rgb {
+            let (s, l) = (clamp_unit(s), clamp_unit(l));
+            let chroma = (1.0 - abs(2.0 * l - 1.0)) * s;
+            from_hue_chroma(wrap_degrees(h) / 60.0, chroma, l - chroma / 2.0)
+        }
+    }
+
+    float_const_fn! {
+        /// Creates a color from its hue (in degrees), saturation and value. Saturation and value
+        /// range from 0 to 1.
+        ///
+        /// *This method is const on Rust 1.82+.*
+        ///
+        /// ```rust
+        /// use owo_colors::Rgb;
+        ///
+        /// assert_eq!(Rgb::from_hsv(30.0, 1.0, 1.0), Rgb(255, 128, 0));
+        /// ```
+        #[must_use]
+        pub fn from_hsv(h: f32, s: f32, v: f32) -> Rgb {
+            let (s, v) = (clamp_unit(s), clamp_unit(v));
+            let chroma = v * s;
+            from_hue_chroma(wrap_degrees(h) / 60.0, chroma, v - chroma)
+        }
+    }
+
+    /// Creates a color from its [OKLCH](https://bottosson.github.io/posts/oklab/) lightness
+    /// (0 to 1), chroma (0 to about 0.37) and hue (in degrees).
+    ///
+    /// Colors that can't be represented in RGB have their chroma reduced until they can, keeping
+    /// their lightness and hue.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// assert_eq!(Rgb::from_oklch(0.628, 0.2577, 29.23), Rgb(255, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> Rgb {
+        Oklab::from_lch(l, c, h).to_rgb()
+    }
+
+    float_const_fn! {
+        /// Returns the hue (in degrees, from 0 to 360), saturation and lightness of this color.
+        ///
+        /// *This method is const on Rust 1.82+.*
+        ///
+        /// ```rust
+        /// use owo_colors::Rgb;
+        ///
+        /// let (h, s, l) = Rgb(51, 102, 153).to_hsl();
+        /// assert_eq!(h, 210.0);
+        /// assert!((s - 0.5).abs() < 0.001);
+        /// assert!((l - 0.4).abs() < 0.001);
+        /// ```
+        #[must_use]
+        pub fn to_hsl(self) -> (f32, f32, f32) {
+            let (hue, max, delta) = hue_max_delta(self);
+            let lightness = max - delta / 2.0;
+            let saturation = if delta == 0.0 {
+                0.0
+            } else {
+                delta / (1.0 - abs(2.0 * lightness - 1.0))
+            };
+            (hue, saturation, lightness)
+        }
+    }
+
+    float_const_fn! {
+        /// Returns the hue (in degrees, from 0 to 360), saturation and value of this color.
+        ///
+        /// *This method is const on Rust 1.82+.*
+        ///
+        /// ```rust
+        /// use owo_colors::Rgb;
+        ///
+        /// assert_eq!(Rgb(255, 0, 255).to_hsv(), (300.0, 1.0, 1.0));
+        /// ```
+        #[must_use]
+        pub fn to_hsv(self) -> (f32, f32, f32) {
+            let (hue, max, delta) = hue_max_delta(self);
+            let saturation = if max == 0.0 { 0.0 } else { delta / max };
+            (hue, saturation, max)
+        }
+    }
+
+    /// Returns the [OKLCH](https://bottosson.github.io/posts/oklab/) lightness, chroma and hue
+    /// (in degrees, from 0 to 360) of this color.
+    ///
+    /// ```rust
+    /// use owo_colors::Rgb;
+    ///
+    /// let (l, c, h) = Rgb(255, 0, 0).to_oklch();
+    /// assert!((l - 0.628).abs() < 0.001);
+    /// assert!((c - 0.2577).abs() < 0.001);
+    /// assert!((h - 29.23).abs() < 0.1);
+    /// ```
+    #[must_use]
+    pub fn to_oklch(self) -> (f32, f32, f32) {
+        Oklab::from_rgb(self).to_lch()
+    }
+}
+
+impl DynColors {
+    float_const_fn! {
+        /// Creates an RGB color from its hue (in degrees), saturation and lightness. See
+        /// [`Rgb::from_hsl`].
+        ///
+        /// *This method is const on Rust 1.82+.*
+        #[must_use]
+        pub fn from_hsl(h: f32, s: f32, l: f32) -> DynColors {
+            let Rgb(r, g, b) = Rgb::from_hsl(h, s, l);
+            DynColors::Rgb(r, g, b)
+        }
+    }
+
+    float_const_fn! {
+        /// Creates an RGB color from its hue (in degrees), saturation and value. See
+        /// [`Rgb::from_hsv`].
+        ///
+        /// *This method is const on Rust 1.82+.*
+        #[must_use]
+        pub fn from_hsv(h: f32, s: f32, v: f32) -> DynColors {
+            let Rgb(r, g, b) = Rgb::from_hsv(h, s, v);
+            DynColors::Rgb(r, g, b)
+        }
+    }
+
+    /// Creates an RGB color from a `#rrggbb` or `#rgb` hex string. See [`Rgb::from_hex`].
+    ///
+    /// ```rust
+    /// use owo_colors::DynColors;
+    ///
+    /// const ACCENT: DynColors = DynColors::from_hex("#DAAC06");
+    /// assert_eq!(ACCENT, DynColors::Rgb(0xDA, 0xAC, 0x06));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hex` isn't a valid hex color.
+    #[must_use]
+    pub const fn from_hex(hex: &str) -> DynColors {
+        let Rgb(r, g, b) = Rgb::from_hex(hex);
+        DynColors::Rgb(r, g, b)
+    }
+
+    /// Creates an RGB color from its OKLCH lightness, chroma and hue (in degrees). See
+    /// [`Rgb::from_oklch`].
+    #[must_use]
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> DynColors {
+        let Rgb(r, g, b) = Rgb::from_oklch(l, c, h);
+        DynColors::Rgb(r, g, b)
+    }
+
+    /// Returns the RGB value of this color.
+    ///
+    /// ANSI colors don't have fixed RGB values, so [`Palette::DEFAULT`] is assumed. Returns `None` for
+    /// [`AnsiColors::Default`](crate::AnsiColors::Default), which depends on the terminal.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, CssColors, DynColors, Rgb};
+    ///
+    /// assert_eq!(DynColors::Css(CssColors::Teal).to_rgb(), Some(Rgb(0, 128, 128)));
+    /// assert_eq!(DynColors::Ansi(AnsiColors::BrightRed).to_rgb(), Some(Rgb(255, 0, 0)));
+    /// assert_eq!(DynColors::Ansi(AnsiColors::Default).to_rgb(), None);
+    /// ```
+    #[must_use]
+    pub fn to_rgb(self) -> Option<Rgb> {
+        self.to_rgb_with(&Palette::DEFAULT)
+    }
+
+    /// Returns the RGB value of this color, when the ANSI colors (and xterm colors 0 to 15) are
+    /// displayed using `palette`. Returns `None` for
+    /// [`AnsiColors::Default`](crate::AnsiColors::Default), which depends on whether the color is
+    /// used as a foreground or a background; see [`Palette::resolve_fg`] for that.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Palette, Rgb};
+    ///
+    /// let yellow = DynColors::Ansi(AnsiColors::Yellow);
+    /// assert_eq!(yellow.to_rgb_with(&Palette::VGA), Some(Rgb(170, 85, 0)));
+    /// ```
+    #[must_use]
+    pub fn to_rgb_with(self, palette: &Palette) -> Option<Rgb> {
+        match self {
+            DynColors::Ansi(AnsiColors::Default) => None,
+            _ => Some(palette.resolve_fg(self)),
+        }
+    }
+
+    /// Returns the hue, saturation and lightness of this color. See [`Rgb::to_hsl`] and
+    /// [`DynColors::to_rgb`].
+    #[must_use]
+    pub fn to_hsl(self) -> Option<(f32, f32, f32)> {
+        self.to_rgb().map(Rgb::to_hsl)
+    }
+
+    /// Returns the hue, saturation and value of this color. See [`Rgb::to_hsv`] and
+    /// [`DynColors::to_rgb`].
+    #[must_use]
+    pub fn to_hsv(self) -> Option<(f32, f32, f32)> {
+        self.to_rgb().map(Rgb::to_hsv)
+    }
+
+    /// Returns the OKLCH lightness, chroma and hue of this color. See [`Rgb::to_oklch`] and
+    /// [`DynColors::to_rgb`].
+    #[must_use]
+    pub fn to_oklch(self) -> Option<(f32, f32, f32)> {
+        self.to_rgb().map(Rgb::to_oklch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hsl() {
+        assert_eq!(Rgb::from_hsl(0.0, 1.0, 0.5), Rgb(255, 0, 0));
+        assert_eq!(Rgb::from_hsl(120.0, 1.0, 0.25), Rgb(0, 128, 0));
+        assert_eq!(Rgb::from_hsl(-120.0, 1.0, 0.5), Rgb(0, 0, 255));
+        assert_eq!(Rgb::from_hsl(480.0, 1.0, 0.5), Rgb(0, 255, 0));
+        assert_eq!(Rgb::from_hsl(0.0, 0.0, 1.0), Rgb(255, 255, 255));
+        assert_eq!(Rgb::from_hsl(200.0, 2.0, -1.0), Rgb(0, 0, 0));
+
+        assert_eq!(Rgb(0, 0, 0).to_hsl(), (0.0, 0.0, 0.0));
+        assert_eq!(Rgb(255, 255, 255).to_hsl(), (0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_hsv() {
+        assert_eq!(Rgb::from_hsv(240.0, 1.0, 1.0), Rgb(0, 0, 255));
+        assert_eq!(Rgb::from_hsv(60.0, 0.5, 0.5), Rgb(128, 128, 64));
+        assert_eq!(Rgb(0, 0, 0).to_hsv(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = Rgb(r, g, b);
+
+                    let (h, s, l) = rgb.to_hsl();
+                    assert_eq!(Rgb::from_hsl(h, s, l), rgb);
+
+                    let (h, s, v) = rgb.to_hsv();
+                    assert_eq!(Rgb::from_hsv(h, s, v), rgb);
+
+                    let (l, c, h) = rgb.to_oklch();
+                    assert_eq!(Rgb::from_oklch(l, c, h), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(const_float_arithmetic)]
+    fn test_const() {
+        const BLUE: Rgb = Rgb::from_hsl(210.0, 0.5, 0.4);
+        const ORANGE: DynColors = DynColors::from_hsv(30.0, 1.0, 1.0);
+        const HSL: (f32, f32, f32) = BLUE.to_hsl();
+
+        assert_eq!(BLUE, Rgb(51, 102, 153));
+        assert_eq!(ORANGE, DynColors::Rgb(255, 128, 0));
+        assert_eq!(HSL, BLUE.to_hsl());
+    }
+}
@@ -23,6 +23,7 @@ macro_rules! xterm_colors {
 
             impl crate::DynColor for XtermColors {
                 fn fmt_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_fg(f));
                     let color = match self {
                         $(
                             XtermColors::$name => concat!("\x1b[38;5;", stringify!($xterm_num), "m"),
@@ -33,6 +34,7 @@ macro_rules! xterm_colors {
                 }
 
                 fn fmt_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if_downgraded!(self.get_dyncolors_fg(), fmt_ansi_bg(f));
                     let color = match self {
                         $(
                             XtermColors::$name => concat!("\x1b[48;5;", stringify!($xterm_num), "m"),
@@ -43,6 +45,7 @@ macro_rules! xterm_colors {
                 }
 
                 fn fmt_raw_ansi_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_fg(f));
                     let color = match self {
                         $(
                             XtermColors::$name => concat!("38;5;", stringify!($xterm_num)),
@@ -53,6 +56,7 @@ macro_rules! xterm_colors {
                 }
 
                 fn fmt_raw_ansi_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if_downgraded!(self.get_dyncolors_fg(), fmt_raw_ansi_bg(f));
                     let color = match self {
                         $(
                             XtermColors::$name => concat!("48;5;", stringify!($xterm_num)),
@@ -92,6 +96,46 @@ macro_rules! xterm_colors {
                     }
                 }
             }
+
+            impl XtermColors {
+                /// All the xterm colors, in order of their index.
+                ///
+                /// ```rust
+                /// use owo_colors::XtermColors;
+                ///
+                /// assert_eq!(XtermColors::ALL.len(), 256);
+                /// assert_eq!(XtermColors::ALL[208], XtermColors::FlushOrange);
+                /// ```
+                pub const ALL: &'static [XtermColors] = &[$(XtermColors::$name,)*];
+
+                /// Returns the name of this color, such as `"FlushOrange"`.
+                ///
+                /// The name can be parsed back into the same color using [`str::parse`].
+                #[must_use]
+                pub const fn name(self) -> &'static str {
+                    match self {
+                        $(
+                            XtermColors::$name => stringify!($name),
+                        )*
+                    }
+                }
+
+                /// Returns the RGB value xterm uses for this color by default.
+                ///
+                /// ```rust
+                /// use owo_colors::{Rgb, XtermColors};
+                ///
+                /// assert_eq!(XtermColors::FlushOrange.rgb(), Rgb(255, 135, 0));
+                /// ```
+                #[must_use]
+                pub const fn rgb(self) -> crate::Rgb {
+                    match self {
+                        $(
+                            XtermColors::$name => crate::Rgb($r, $g, $b),
+                        )*
+                    }
+                }
+            }
         }
 
         $(
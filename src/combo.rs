@@ -1,6 +1,9 @@
 use crate::{BgColorDisplay, Color, FgColorDisplay};
-use crate::{BgDynColorDisplay, DynColor, FgDynColorDisplay, Style, Styled, colors};
+use crate::{
+    BgDynColorDisplay, DynColor, FgDynColorDisplay, Style, Styled, UnderlineColorDisplay, colors,
+};
 
+use crate::resets::is_granular;
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -23,12 +26,12 @@ macro_rules! impl_fmt_for_combo {
                 #[inline(always)]
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f.write_str("\x1b[")?;
-                    f.write_str(Fg::RAW_ANSI_FG)?;
+                    colors::fmt_raw_ansi_fg::<Fg>(f)?;
                     f.write_str(";")?;
-                    f.write_str(Bg::RAW_ANSI_BG)?;
+                    colors::fmt_raw_ansi_bg::<Bg>(f)?;
                     f.write_str("m")?;
                     <T as $trait>::fmt(&self.0, f)?;
-                    f.write_str("\x1b[0m")
+                    f.write_str(if is_granular() { "\x1b[39;49m" } else { "\x1b[0m" })
                 }
             }
         )*
@@ -43,7 +46,7 @@ macro_rules! impl_fmt_for_combo {
                     self.2.fmt_raw_ansi_bg(f)?;
                     f.write_str("m")?;
                     <T as $trait>::fmt(&self.0, f)?;
-                    f.write_str("\x1b[0m")
+                    f.write_str(if is_granular() { "\x1b[39;49m" } else { "\x1b[0m" })
                 }
             }
         )*
@@ -741,6 +744,61 @@ impl<'a, Bg: DynColor + Copy, T: ?Sized> BgDynColorDisplay<'a, Bg, T> {
     }
 }
 
+impl<'a, Color: DynColor + Copy, T: ?Sized> UnderlineColorDisplay<'a, Color, T> {
+    /// Create a new [`UnderlineColorDisplay`], from a reference to a type which implements
+    /// [`DynColor`].
+    ///
+    /// This is a const function: in non-const contexts, [`OwoColorize::underline_color`] may be
+    /// more convenient.
+    ///
+    /// # Example
+    ///
+    /// Usage in const contexts:
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, UnderlineColorDisplay};
+    ///
+    /// const RED_UNDERLINE: UnderlineColorDisplay<AnsiColors, str> =
+    ///    UnderlineColorDisplay::new("red underline color", AnsiColors::Red);
+    ///
+    /// println!("{}", RED_UNDERLINE);
+    /// # assert_eq!(format!("{}", RED_UNDERLINE), "\x1b[58;5;1mred underline color\x1b[59m");
+    /// ```
+    pub const fn new(thing: &'a T, color: Color) -> Self {
+        Self(thing, color)
+    }
+
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, OwoColorize, XtermColors};
+    ///
+    /// fn is_error() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_error() {
+    ///     "hello".underline_color(AnsiColors::Red).into_styled()
+    /// } else {
+    ///     "hello".underline_color(XtermColors::FlushOrange).into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(format!("{}", styled_str), "\x1b[58;5;1mhello\x1b[0m");
+    /// ```
+    pub fn into_styled(self) -> Styled<&'a T> {
+        let Self(target, color) = self;
+        let style = Style::new().underline_color(color);
+        Styled { style, target }
+    }
+}
+
 impl<'a, Fg: DynColor + Copy, Bg: DynColor + Copy, T: ?Sized> ComboDynColorDisplay<'a, Fg, Bg, T> {
     /// Create a new [`ComboDynColorDisplay`], from a pair of types which implement
     /// [`DynColor`].
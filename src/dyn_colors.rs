@@ -67,49 +67,498 @@ impl DynColor for DynColors {
     }
 }
 
+impl DynColors {
+    /// Writes the raw ANSI code to set the underline color to this color, without the ANSI
+    /// delimiters.
+    ///
+    /// Underline colors can only be given as an xterm index (`58;5;n`) or as RGB (`58;2;r;g;b`),
+    /// so ANSI colors are written as their index in the xterm palette, and
+    /// [`AnsiColors::Default`] resets the underline color with `59`.
+    pub(crate) fn fmt_raw_ansi_underline(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "supports-colors")]
+        let color = crate::supports_colors::downgrade(*self).unwrap_or(*self);
+        #[cfg(not(feature = "supports-colors"))]
+        let color = *self;
+
+        match color {
+            DynColors::Ansi(ansi) => match crate::colors::palette::ansi_index(ansi) {
+                Some(index) => write!(f, "58;5;{}", index),
+                None => f.write_str("59"),
+            },
+            DynColors::Css(css) => {
+                let Rgb(r, g, b) = css.rgb();
+                write!(f, "58;2;{};{};{}", r, g, b)
+            }
+            DynColors::Xterm(xterm) => write!(f, "58;5;{}", u8::from(xterm)),
+            DynColors::Rgb(r, g, b) => write!(f, "58;2;{};{};{}", r, g, b),
+        }
+    }
+}
+
 /// An error for when the color can not be parsed from a string at runtime
-#[derive(Debug)]
-pub struct ParseColorError;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseColorError {
+    kind: ParseColorErrorKind,
+    position: usize,
+}
+
+/// The reason a color could not be parsed, see [`ParseColorError::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseColorErrorKind {
+    /// The string is empty or only contains whitespace
+    Empty,
+    /// A hex color contains a character which is not a hex digit
+    InvalidHexDigit,
+    /// A hex color does not have 3 or 6 digits
+    InvalidHexLength,
+    /// The name does not match any known color
+    UnknownName,
+    /// The function is not one of `rgb`, `hsl` or `ansi`
+    UnknownFunction,
+    /// A function is missing its closing parenthesis, or has text after it
+    UnclosedFunction,
+    /// A function has the wrong number of arguments
+    WrongArgumentCount,
+    /// A number is not valid
+    InvalidNumber,
+    /// A number is out of range, such as an RGB channel above 255
+    OutOfRange,
+}
+
+impl ParseColorError {
+    /// Creates an error of the given kind at a byte offset in the parsed string, for
+    /// implementations of [`FromStr`](core::str::FromStr) which build on
+    /// [`DynColors::from_str`](core::str::FromStr::from_str).
+    #[must_use]
+    pub const fn new(kind: ParseColorErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// The reason the color could not be parsed
+    #[must_use]
+    pub const fn kind(&self) -> ParseColorErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the parsed string at which the error was found
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseColorErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseColorErrorKind::Empty => "empty color",
+            ParseColorErrorKind::InvalidHexDigit => "invalid hex digit",
+            ParseColorErrorKind::InvalidHexLength => "hex color must have 3 or 6 digits",
+            ParseColorErrorKind::UnknownName => "unknown color name",
+            ParseColorErrorKind::UnknownFunction => "unknown color function",
+            ParseColorErrorKind::UnclosedFunction => "expected `)` at the end of the color",
+            ParseColorErrorKind::WrongArgumentCount => "wrong number of arguments",
+            ParseColorErrorKind::InvalidNumber => "invalid number",
+            ParseColorErrorKind::OutOfRange => "number out of range",
+        })
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+/// Returns true if `input` is `name`, ignoring ASCII case, spaces, hyphens and underscores.
+pub(crate) fn name_matches(input: &str, name: &str) -> bool {
+    let significant = |c: &u8| !matches!(c, b' ' | b'-' | b'_');
+    input
+        .bytes()
+        .filter(significant)
+        .map(|c| c.to_ascii_lowercase())
+        .eq(name
+            .bytes()
+            .filter(significant)
+            .map(|c| c.to_ascii_lowercase()))
+}
+
+/// A part of the string being parsed, along with its position in the whole string.
+#[derive(Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Token<'a> {
+    fn error(&self, kind: ParseColorErrorKind) -> ParseColorError {
+        ParseColorError::new(kind, self.position)
+    }
+
+    fn trim(self) -> Self {
+        let trimmed = self.text.trim_start();
+        Token {
+            text: trimmed.trim_end(),
+            position: self.position + (self.text.len() - trimmed.len()),
+        }
+    }
+
+    fn slice(self, start: usize, end: usize) -> Self {
+        Token {
+            text: &self.text[start..end],
+            position: self.position + start,
+        }
+    }
+
+    /// Parses a number, which may be followed by `suffix`.
+    fn number(self, suffix: &str) -> Result<f32, ParseColorError> {
+        let text = self.text.strip_suffix(suffix).unwrap_or(self.text);
+        match text.parse::<f32>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            _ => Err(self.error(ParseColorErrorKind::InvalidNumber)),
+        }
+    }
+
+    /// Parses a number in the range `0..=max`, which may be followed by `suffix`.
+    fn number_up_to(self, max: f32, suffix: &str) -> Result<f32, ParseColorError> {
+        let number = self.number(suffix)?;
+        if (0.0..=max).contains(&number) {
+            Ok(number)
+        } else {
+            Err(self.error(ParseColorErrorKind::OutOfRange))
+        }
+    }
+
+    fn xterm_index(self) -> Result<XtermColors, ParseColorError> {
+        if self.text.is_empty() || !self.text.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(self.error(ParseColorErrorKind::InvalidNumber));
+        }
+        self.text
+            .parse::<u8>()
+            .map(XtermColors::from)
+            .map_err(|_| self.error(ParseColorErrorKind::OutOfRange))
+    }
+}
+
+fn parse_hex(token: Token<'_>) -> Result<DynColors, ParseColorError> {
+    let digits = token.slice(1, token.text.len());
+    if let Some(invalid) = digits.text.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::new(
+            ParseColorErrorKind::InvalidHexDigit,
+            digits.position + invalid,
+        ));
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&digits.text[i..=i], 16).unwrap_or_default();
+    match digits.text.len() {
+        3 => Ok(DynColors::Rgb(
+            digit(0) * 0x11,
+            digit(1) * 0x11,
+            digit(2) * 0x11,
+        )),
+        6 => Ok(DynColors::Rgb(
+            digit(0) << 4 | digit(1),
+            digit(2) << 4 | digit(3),
+            digit(4) << 4 | digit(5),
+        )),
+        _ => Err(token.error(ParseColorErrorKind::InvalidHexLength)),
+    }
+}
+
+/// The functions which can be used to write a color.
+#[derive(Clone, Copy)]
+enum Function {
+    Rgb,
+    Hsl,
+    Ansi,
+}
+
+impl Function {
+    const fn name(self) -> &'static str {
+        match self {
+            Function::Rgb => "rgb",
+            Function::Hsl => "hsl",
+            Function::Ansi => "ansi",
+        }
+    }
+}
+
+fn parse_function(token: Token<'_>, open: usize) -> Result<DynColors, ParseColorError> {
+    let name = token.slice(0, open).trim();
+    let (function, expected) = [(Function::Rgb, 3), (Function::Hsl, 3), (Function::Ansi, 1)]
+        .into_iter()
+        .find(|(function, _)| name.text.eq_ignore_ascii_case(function.name()))
+        .ok_or_else(|| name.error(ParseColorErrorKind::UnknownFunction))?;
+
+    if !token.text.ends_with(')') {
+        return Err(ParseColorError::new(
+            ParseColorErrorKind::UnclosedFunction,
+            token.position + token.text.len(),
+        ));
+    }
+    let close = token.text.len() - 1;
+    let arguments = token.slice(open + 1, close);
+    if let Some(extra) = arguments.text.find(['(', ')']) {
+        return Err(ParseColorError::new(
+            ParseColorErrorKind::UnclosedFunction,
+            arguments.position + extra,
+        ));
+    }
+
+    // Arguments are separated by commas, or by whitespace if there aren't any commas
+    let mut args = [arguments; 3];
+    let mut count = 0;
+    let separator = if arguments.text.contains(',') {
+        |c: char| c == ','
+    } else {
+        char::is_whitespace
+    };
+    let mut start = 0;
+    for (end, matched) in arguments
+        .text
+        .match_indices(separator)
+        .chain(core::iter::once((arguments.text.len(), "")))
+    {
+        let arg = arguments.slice(start, end).trim();
+        start = end + matched.len();
+        if arg.text.is_empty() && !arguments.text.contains(',') {
+            continue;
+        }
+        if count == args.len() {
+            return Err(arg.error(ParseColorErrorKind::WrongArgumentCount));
+        }
+        args[count] = arg;
+        count += 1;
+    }
+
+    if count != expected {
+        return Err(ParseColorError::new(
+            ParseColorErrorKind::WrongArgumentCount,
+            token.position + close,
+        ));
+    }
+
+    match function {
+        Function::Rgb => {
+            let mut channels = [0; 3];
+            for (channel, arg) in channels.iter_mut().zip(args) {
+                let max = if arg.text.ends_with('%') {
+                    100.0
+                } else {
+                    255.0
+                };
+                let value = arg.number_up_to(max, "%")?;
+                *channel = (value * 255.0 / max + 0.5) as u8;
+            }
+            Ok(DynColors::Rgb(channels[0], channels[1], channels[2]))
+        }
+        Function::Hsl => {
+            let hue = args[0].number("deg")?;
+            let saturation = args[1].number_up_to(100.0, "%")?;
+            let lightness = args[2].number_up_to(100.0, "%")?;
+            Ok(DynColors::from_hsl(
+                hue,
+                saturation / 100.0,
+                lightness / 100.0,
+            ))
+        }
+        Function::Ansi => Ok(DynColors::Xterm(args[0].xterm_index()?)),
+    }
+}
+
+/// The names of the ANSI colors. When a color has several names, the first one is used to
+/// display it.
+const ANSI_NAMES: [(&str, AnsiColors); 18] = [
+    ("black", AnsiColors::Black),
+    ("red", AnsiColors::Red),
+    ("green", AnsiColors::Green),
+    ("yellow", AnsiColors::Yellow),
+    ("blue", AnsiColors::Blue),
+    ("magenta", AnsiColors::Magenta),
+    ("purple", AnsiColors::Magenta),
+    ("cyan", AnsiColors::Cyan),
+    ("white", AnsiColors::White),
+    ("bright-black", AnsiColors::BrightBlack),
+    ("bright-red", AnsiColors::BrightRed),
+    ("bright-green", AnsiColors::BrightGreen),
+    ("bright-yellow", AnsiColors::BrightYellow),
+    ("bright-blue", AnsiColors::BrightBlue),
+    ("bright-magenta", AnsiColors::BrightMagenta),
+    ("bright-cyan", AnsiColors::BrightCyan),
+    ("bright-white", AnsiColors::BrightWhite),
+    ("default", AnsiColors::Default),
+];
+
+fn parse_name(token: Token<'_>) -> Result<DynColors, ParseColorError> {
+    if let Some((_, ansi)) = ANSI_NAMES
+        .iter()
+        .find(|(name, _)| name_matches(token.text, name))
+    {
+        return Ok(DynColors::Ansi(*ansi));
+    }
+
+    token.text.parse().map(DynColors::Css)
+}
+
+/// Parses a CSS color from its name, such as `AliceBlue`.
+///
+/// Names are case-insensitive, and spaces, hyphens and underscores are ignored, so `alice-blue`
+/// is the same color.
+///
+/// ```rust
+/// use owo_colors::{CssColors, ParseColorErrorKind};
+///
+/// assert_eq!("rebeccapurple".parse(), Ok(CssColors::RebeccaPurple));
+/// assert_eq!(
+///     "not a color".parse::<CssColors>().unwrap_err().kind(),
+///     ParseColorErrorKind::UnknownName
+/// );
+/// ```
+impl core::str::FromStr for CssColors {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = Token {
+            text: s,
+            position: 0,
+        }
+        .trim();
+
+        CssColors::ALL
+            .iter()
+            .copied()
+            .find(|css| name_matches(token.text, css.name()))
+            .ok_or_else(|| token.error(ParseColorErrorKind::UnknownName))
+    }
+}
+
+/// Parses an xterm color from its name, such as `FlushOrange`, or from its index, such as `208`.
+///
+/// Names are case-insensitive, and spaces, hyphens and underscores are ignored, so
+/// `flush-orange` is the same color.
+///
+/// ```rust
+/// use owo_colors::XtermColors;
+///
+/// assert_eq!("flush orange".parse(), Ok(XtermColors::FlushOrange));
+/// assert_eq!("208".parse(), Ok(XtermColors::FlushOrange));
+/// ```
+impl core::str::FromStr for XtermColors {
+    type Err = ParseColorError;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = Token {
+            text: s,
+            position: 0,
+        }
+        .trim();
+
+        if token.text.is_empty() {
+            Err(token.error(ParseColorErrorKind::Empty))
+        } else if token.text.bytes().all(|c| c.is_ascii_digit()) {
+            token.xterm_index()
+        } else {
+            XtermColors::ALL
+                .iter()
+                .copied()
+                .find(|xterm| name_matches(token.text, xterm.name()))
+                .ok_or_else(|| token.error(ParseColorErrorKind::UnknownName))
+        }
+    }
+}
+
+/// Parses a color from a string.
+///
+/// The following formats are supported, ignoring case and surrounding whitespace:
+///
+/// * `#rgb` and `#rrggbb` hex colors
+/// * `rgb(r, g, b)`, with channels from 0 to 255 or percentages
+/// * `hsl(h, s%, l%)`, with the hue in degrees
+/// * xterm color indices, such as `208` or `ansi(208)`
+/// * ANSI color names, such as `red` or `bright red`, and `default`
+/// * CSS color names, such as `rebeccapurple`
+///
+/// Function arguments can be separated by commas or whitespace. In names, spaces, hyphens and
+/// underscores are ignored, so `bright-red`, `bright_red` and `BrightRed` are all the same
+/// color. ANSI names take precedence over CSS names.
+///
+/// ```rust
+/// use owo_colors::{AnsiColors, CssColors, DynColors, ParseColorErrorKind, XtermColors};
+///
+/// assert_eq!("#f80".parse(), Ok(DynColors::Rgb(255, 136, 0)));
+/// assert_eq!("rgb(255, 136, 0)".parse(), Ok(DynColors::Rgb(255, 136, 0)));
+/// assert_eq!("hsl(120deg 100% 25%)".parse(), Ok(DynColors::Rgb(0, 128, 0)));
+/// assert_eq!("ansi(208)".parse(), Ok(DynColors::Xterm(XtermColors::FlushOrange)));
+/// assert_eq!("Bright_Red".parse(), Ok(DynColors::Ansi(AnsiColors::BrightRed)));
+/// assert_eq!("light-sea-green".parse(), Ok(DynColors::Css(CssColors::LightSeaGreen)));
+///
+/// let error = "rgb(255, 300, 0)".parse::<DynColors>().unwrap_err();
+/// assert_eq!(error.kind(), ParseColorErrorKind::OutOfRange);
+/// assert_eq!(error.position(), 9);
+/// ```
 impl core::str::FromStr for DynColors {
     type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.chars().next().ok_or(ParseColorError)? == '#' {
-            match s.len() {
-                4 => {
-                    // TODO
-                    Err(ParseColorError)
+        let token = Token {
+            text: s,
+            position: 0,
+        }
+        .trim();
+
+        if token.text.is_empty() {
+            Err(token.error(ParseColorErrorKind::Empty))
+        } else if token.text.starts_with('#') {
+            parse_hex(token)
+        } else if let Some(open) = token.text.find('(') {
+            parse_function(token, open)
+        } else if token.text.bytes().all(|c| c.is_ascii_digit()) {
+            token.xterm_index().map(DynColors::Xterm)
+        } else {
+            parse_name(token)
+        }
+    }
+}
+
+/// Displays the color in a form which can be parsed back with [`str::parse`]: hex for RGB
+/// colors, the index for xterm colors and the name for ANSI and CSS colors, such as `bright-red`
+/// or `RebeccaPurple`.
+///
+/// CSS colors which share a name with an ANSI color, such as `Red`, are displayed as hex, since
+/// their names are parsed as ANSI colors.
+///
+/// ```rust
+/// use owo_colors::{AnsiColors, CssColors, DynColors, XtermColors};
+///
+/// assert_eq!(DynColors::Ansi(AnsiColors::BrightRed).to_string(), "bright-red");
+/// assert_eq!(DynColors::Css(CssColors::RebeccaPurple).to_string(), "RebeccaPurple");
+/// assert_eq!(DynColors::Css(CssColors::Red).to_string(), "#ff0000");
+/// assert_eq!(DynColors::Xterm(XtermColors::FlushOrange).to_string(), "208");
+/// assert_eq!(DynColors::Rgb(255, 136, 0).to_string(), "#ff8800");
+/// ```
+impl fmt::Display for DynColors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DynColors::Ansi(ansi) => {
+                let name = ANSI_NAMES
+                    .iter()
+                    .find(|(_, color)| *color == ansi)
+                    .map_or("default", |(name, _)| name);
+                f.write_str(name)
+            }
+            DynColors::Css(css) => {
+                if ANSI_NAMES
+                    .iter()
+                    .any(|(name, _)| name_matches(css.name(), name))
+                {
+                    let Rgb(r, g, b) = css.rgb();
+                    write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+                } else {
+                    f.write_str(css.name())
                 }
-                7 => Ok(Self::Rgb(
-                    u8::from_str_radix(&s[1..3], 16).or(Err(ParseColorError))?,
-                    u8::from_str_radix(&s[3..5], 16).or(Err(ParseColorError))?,
-                    u8::from_str_radix(&s[5..7], 16).or(Err(ParseColorError))?,
-                )),
-                _ => Err(ParseColorError),
             }
-        } else {
-            let ansi = match s {
-                "black" => AnsiColors::Black,
-                "red" => AnsiColors::Red,
-                "green" => AnsiColors::Green,
-                "yellow" => AnsiColors::Yellow,
-                "blue" => AnsiColors::Blue,
-                "magenta" | "purple" => AnsiColors::Magenta,
-                "cyan" => AnsiColors::Cyan,
-                "white" => AnsiColors::White,
-                "bright black" => AnsiColors::BrightBlack,
-                "bright red" => AnsiColors::BrightRed,
-                "bright green" => AnsiColors::BrightGreen,
-                "bright yellow" => AnsiColors::BrightYellow,
-                "bright blue" => AnsiColors::BrightBlue,
-                "bright magenta" => AnsiColors::BrightMagenta,
-                "bright cyan" => AnsiColors::BrightCyan,
-                "bright white" => AnsiColors::BrightWhite,
-                _ => return Err(ParseColorError),
-            };
-
-            Ok(Self::Ansi(ansi))
+            DynColors::Xterm(xterm) => write!(f, "{}", u8::from(xterm)),
+            DynColors::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
         }
     }
 }
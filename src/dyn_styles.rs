@@ -1,12 +1,15 @@
-use crate::{AnsiColors, Color, DynColor, DynColors};
-use core::fmt;
+use crate::{AnsiColors, Color, DynColor, DynColors, Palette};
+use core::{fmt, ops};
 
 #[cfg(doc)]
 use crate::OwoColorize;
 
 /// A runtime-configurable text effect for use with [`Style`]
+///
+/// More effects may be added in the future, so matching on an `Effect` needs a wildcard arm.
 #[allow(missing_docs)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Effect {
     Bold,
     Dimmed,
@@ -17,6 +20,85 @@ pub enum Effect {
     Reversed,
     Hidden,
     Strikethrough,
+    DoubleUnderline,
+    CurlyUnderline,
+    DottedUnderline,
+    DashedUnderline,
+    Overline,
+    Framed,
+    Encircled,
+    Superscript,
+    Subscript,
+    Fraktur,
+    /// The primary font or an alternate font (SGR 10 to 19)
+    Font(Font),
+}
+
+/// One of the fonts selected by SGR 10 to 19, for use with [`Effect::Font`] and [`Style::font`]
+///
+/// Which fonts are available depends on the terminal, and most terminals ignore them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Font {
+    /// The primary font (SGR 10)
+    Primary,
+    /// Alternate font 1 (SGR 11)
+    Alt1,
+    /// Alternate font 2 (SGR 12)
+    Alt2,
+    /// Alternate font 3 (SGR 13)
+    Alt3,
+    /// Alternate font 4 (SGR 14)
+    Alt4,
+    /// Alternate font 5 (SGR 15)
+    Alt5,
+    /// Alternate font 6 (SGR 16)
+    Alt6,
+    /// Alternate font 7 (SGR 17)
+    Alt7,
+    /// Alternate font 8 (SGR 18)
+    Alt8,
+    /// Alternate font 9 (SGR 19)
+    Alt9,
+}
+
+impl Font {
+    /// All the fonts, in order of their number
+    pub const ALL: [Font; 10] = [
+        Font::Primary,
+        Font::Alt1,
+        Font::Alt2,
+        Font::Alt3,
+        Font::Alt4,
+        Font::Alt5,
+        Font::Alt6,
+        Font::Alt7,
+        Font::Alt8,
+        Font::Alt9,
+    ];
+
+    /// Returns the font with the given number: 0 for the primary font, or 1 to 9 for an alternate
+    /// font. Returns `None` if `number` is greater than 9.
+    ///
+    /// ```rust
+    /// use owo_colors::Font;
+    ///
+    /// assert_eq!(Font::new(3), Some(Font::Alt3));
+    /// assert_eq!(Font::new(10), None);
+    /// ```
+    #[must_use]
+    pub const fn new(number: u8) -> Option<Self> {
+        if (number as usize) < Self::ALL.len() {
+            Some(Self::ALL[number as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the font's number: 0 for the primary font, or 1 to 9 for an alternate font.
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        self as u8
+    }
 }
 
 macro_rules! color_methods {
@@ -42,9 +124,9 @@ macro_rules! color_methods {
 }
 
 macro_rules! style_methods {
-    ($(#[$meta:meta] ($name:ident, $set_name:ident)),* $(,)?) => {
+    ($($(#[$meta:meta])+ ($name:ident, $set_name:ident)),* $(,)?) => {
         $(
-            #[$meta]
+            $(#[$meta])+
             #[must_use]
             pub const fn $name(mut self) -> Self {
                 self.style_flags = self.style_flags.$set_name(true);
@@ -83,13 +165,15 @@ pub struct Styled<T> {
 pub struct Style {
     pub(crate) fg: Option<DynColors>,
     pub(crate) bg: Option<DynColors>,
+    pub(crate) underline_color: Option<DynColors>,
     pub(crate) bold: bool,
     pub(crate) style_flags: StyleFlags,
+    pub(crate) hyperlink: Option<&'static str>,
 }
 
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub(crate) struct StyleFlags(pub(crate) u8);
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct StyleFlags(pub(crate) u32);
 
 impl StyleFlags {
     #[must_use]
@@ -99,17 +183,43 @@ impl StyleFlags {
     }
 }
 
-const DIMMED_SHIFT: u8 = 0;
-const ITALIC_SHIFT: u8 = 1;
-const UNDERLINE_SHIFT: u8 = 2;
-const BLINK_SHIFT: u8 = 3;
-const BLINK_FAST_SHIFT: u8 = 4;
-const REVERSED_SHIFT: u8 = 5;
-const HIDDEN_SHIFT: u8 = 6;
-const STRIKETHROUGH_SHIFT: u8 = 7;
+const DIMMED_SHIFT: u32 = 0;
+const ITALIC_SHIFT: u32 = 1;
+const UNDERLINE_SHIFT: u32 = 2;
+const BLINK_SHIFT: u32 = 3;
+const BLINK_FAST_SHIFT: u32 = 4;
+const REVERSED_SHIFT: u32 = 5;
+const HIDDEN_SHIFT: u32 = 6;
+const STRIKETHROUGH_SHIFT: u32 = 7;
+const DOUBLE_UNDERLINE_SHIFT: u32 = 8;
+const CURLY_UNDERLINE_SHIFT: u32 = 9;
+const DOTTED_UNDERLINE_SHIFT: u32 = 10;
+const DASHED_UNDERLINE_SHIFT: u32 = 11;
+
+/// A terminal only draws one kind of underline at a time, so setting one of these flags clears
+/// the others.
+const UNDERLINE_MASK: u32 = (1 << UNDERLINE_SHIFT)
+    | (1 << DOUBLE_UNDERLINE_SHIFT)
+    | (1 << CURLY_UNDERLINE_SHIFT)
+    | (1 << DOTTED_UNDERLINE_SHIFT)
+    | (1 << DASHED_UNDERLINE_SHIFT);
+
+const OVERLINE_SHIFT: u32 = 12;
+const FRAMED_SHIFT: u32 = 13;
+const ENCIRCLED_SHIFT: u32 = 14;
+const SUPERSCRIPT_SHIFT: u32 = 15;
+const SUBSCRIPT_SHIFT: u32 = 16;
+const FRAKTUR_SHIFT: u32 = 17;
+
+/// The font is stored as 4 bits: 0 if no font is set, or the font number plus one.
+const FONT_SHIFT: u32 = 18;
+const FONT_MASK: u32 = 0b1111 << FONT_SHIFT;
+
+const FRAME_MASK: u32 = (1 << FRAMED_SHIFT) | (1 << ENCIRCLED_SHIFT);
+const SCRIPT_MASK: u32 = (1 << SUPERSCRIPT_SHIFT) | (1 << SUBSCRIPT_SHIFT);
 
 macro_rules! style_flags_methods {
-    ($(($shift:ident, $name:ident, $set_name:ident)),* $(,)?) => {
+    ($(($shift:ident, $name:ident, $set_name:ident $(, exclusive = $mask:ident)?)),* $(,)?) => {
         $(
             #[must_use]
             const fn $name(&self) -> bool {
@@ -118,7 +228,12 @@ macro_rules! style_flags_methods {
 
             #[must_use]
             const fn $set_name(mut self, $name: bool) -> Self {
-                self.0 = (self.0 & !(1 << $shift)) | (($name as u8) << $shift);
+                $(
+                    if $name {
+                        self.0 &= !$mask;
+                    }
+                )?
+                self.0 = (self.0 & !(1 << $shift)) | (($name as u32) << $shift);
                 self
             }
         )*
@@ -133,12 +248,99 @@ impl StyleFlags {
     style_flags_methods! {
         (DIMMED_SHIFT, dimmed, set_dimmed),
         (ITALIC_SHIFT, italic, set_italic),
-        (UNDERLINE_SHIFT, underline, set_underline),
+        (UNDERLINE_SHIFT, underline, set_underline, exclusive = UNDERLINE_MASK),
         (BLINK_SHIFT, blink, set_blink),
         (BLINK_FAST_SHIFT, blink_fast, set_blink_fast),
         (REVERSED_SHIFT, reversed, set_reversed),
         (HIDDEN_SHIFT, hidden, set_hidden),
         (STRIKETHROUGH_SHIFT, strikethrough, set_strikethrough),
+        (DOUBLE_UNDERLINE_SHIFT, double_underline, set_double_underline, exclusive = UNDERLINE_MASK),
+        (CURLY_UNDERLINE_SHIFT, curly_underline, set_curly_underline, exclusive = UNDERLINE_MASK),
+        (DOTTED_UNDERLINE_SHIFT, dotted_underline, set_dotted_underline, exclusive = UNDERLINE_MASK),
+        (DASHED_UNDERLINE_SHIFT, dashed_underline, set_dashed_underline, exclusive = UNDERLINE_MASK),
+        (OVERLINE_SHIFT, overline, set_overline),
+        (FRAMED_SHIFT, framed, set_framed, exclusive = FRAME_MASK),
+        (ENCIRCLED_SHIFT, encircled, set_encircled, exclusive = FRAME_MASK),
+        (SUPERSCRIPT_SHIFT, superscript, set_superscript, exclusive = SCRIPT_MASK),
+        (SUBSCRIPT_SHIFT, subscript, set_subscript, exclusive = SCRIPT_MASK),
+        // Fraktur is a font too, so it replaces any other font
+        (FRAKTUR_SHIFT, fraktur, set_fraktur, exclusive = FONT_MASK),
+    }
+
+    /// Returns the font, if one is set.
+    #[must_use]
+    pub(crate) const fn font(&self) -> Option<Font> {
+        match (self.0 & FONT_MASK) >> FONT_SHIFT {
+            0 => None,
+            font => Font::new(font as u8 - 1),
+        }
+    }
+
+    /// Returns these flags with `other`'s flags added. Where both set a flag from a group which
+    /// replace each other, such as the kinds of underline, `other`'s flag is kept.
+    #[must_use]
+    pub(crate) const fn patch(self, other: StyleFlags) -> Self {
+        let groups = [
+            UNDERLINE_MASK,
+            FRAME_MASK,
+            SCRIPT_MASK,
+            FONT_MASK | (1 << FRAKTUR_SHIFT),
+        ];
+
+        let mut flags = self.0;
+        let mut i = 0;
+        while i < groups.len() {
+            if other.0 & groups[i] != 0 {
+                flags &= !groups[i];
+            }
+            i += 1;
+        }
+        Self(flags | other.0)
+    }
+
+    /// Returns the flags set in both these flags and `other`.
+    #[must_use]
+    const fn intersect(self, other: StyleFlags) -> Self {
+        let font = match (self.font(), other.font()) {
+            (Some(font), Some(other_font)) if font as u8 == other_font as u8 => Some(font),
+            _ => None,
+        };
+        Self(self.0 & other.0 & !FONT_MASK).set_font(font)
+    }
+
+    /// Returns the flags set in these flags but not in `other`.
+    #[must_use]
+    const fn difference(self, other: StyleFlags) -> Self {
+        let font = match (self.font(), other.font()) {
+            (Some(font), Some(other_font)) if font as u8 == other_font as u8 => None,
+            (font, _) => font,
+        };
+        Self(self.0 & !other.0 & !FONT_MASK).set_font(font)
+    }
+
+    /// Returns the font which has to be set to go from the `from` flags to these flags, if any.
+    ///
+    /// Changing the font doesn't require a reset, as setting a font replaces the previous one and
+    /// font 0 is the primary font.
+    #[must_use]
+    pub(crate) const fn font_transition(&self, from: &StyleFlags) -> Option<Font> {
+        match (self.font(), from.font()) {
+            (Some(font), Some(from_font)) if font as u8 != from_font as u8 => Some(font),
+            (Some(font), None) => Some(font),
+            (None, Some(_)) if !self.fraktur() => Some(Font::Primary),
+            _ => None,
+        }
+    }
+
+    /// Sets the font, replacing Fraktur if it was set.
+    #[must_use]
+    pub(crate) const fn set_font(mut self, font: Option<Font>) -> Self {
+        self.0 &= !FONT_MASK;
+        if let Some(font) = font {
+            self.0 &= !(1 << FRAKTUR_SHIFT);
+            self.0 |= (font as u32 + 1) << FONT_SHIFT;
+        }
+        self
     }
 }
 
@@ -148,6 +350,270 @@ impl Default for StyleFlags {
     }
 }
 
+/// A set of [`Effect`]s, as applied by a [`Style`]. Returned by [`Style::effect_set`].
+///
+/// Effects which a terminal can't display together replace each other, in the same way as when
+/// they're applied to a [`Style`]: inserting [`Effect::CurlyUnderline`] removes
+/// [`Effect::Underline`], for example.
+///
+/// ```rust
+/// use owo_colors::{Effect, EffectSet};
+///
+/// let mut effects: EffectSet = [Effect::Bold, Effect::Underline].into_iter().collect();
+/// effects.insert(Effect::CurlyUnderline);
+/// assert!(effects.contains(Effect::Bold));
+/// assert!(!effects.contains(Effect::Underline));
+/// assert_eq!(
+///     effects.iter().collect::<Vec<_>>(),
+///     [Effect::Bold, Effect::CurlyUnderline],
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct EffectSet {
+    bold: bool,
+    flags: StyleFlags,
+}
+
+/// Every effect except [`Effect::Font`], in the order they're iterated in.
+const FLAG_EFFECTS: [Effect; 19] = [
+    Effect::Bold,
+    Effect::Dimmed,
+    Effect::Italic,
+    Effect::Underline,
+    Effect::Blink,
+    Effect::BlinkFast,
+    Effect::Reversed,
+    Effect::Hidden,
+    Effect::Strikethrough,
+    Effect::DoubleUnderline,
+    Effect::CurlyUnderline,
+    Effect::DottedUnderline,
+    Effect::DashedUnderline,
+    Effect::Overline,
+    Effect::Framed,
+    Effect::Encircled,
+    Effect::Superscript,
+    Effect::Subscript,
+    Effect::Fraktur,
+];
+
+impl EffectSet {
+    /// Create a new, empty set of effects
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bold: false,
+            flags: StyleFlags::new(),
+        }
+    }
+
+    /// Returns true if the set contains `effect`. For [`Effect::Font`], the font has to match
+    /// too.
+    #[must_use]
+    pub const fn contains(&self, effect: Effect) -> bool {
+        use Effect::*;
+        match effect {
+            Bold => self.bold,
+            Dimmed => self.flags.dimmed(),
+            Italic => self.flags.italic(),
+            Underline => self.flags.underline(),
+            Blink => self.flags.blink(),
+            BlinkFast => self.flags.blink_fast(),
+            Reversed => self.flags.reversed(),
+            Hidden => self.flags.hidden(),
+            Strikethrough => self.flags.strikethrough(),
+            DoubleUnderline => self.flags.double_underline(),
+            CurlyUnderline => self.flags.curly_underline(),
+            DottedUnderline => self.flags.dotted_underline(),
+            DashedUnderline => self.flags.dashed_underline(),
+            Overline => self.flags.overline(),
+            Framed => self.flags.framed(),
+            Encircled => self.flags.encircled(),
+            Superscript => self.flags.superscript(),
+            Subscript => self.flags.subscript(),
+            Fraktur => self.flags.fraktur(),
+            Font(font) => matches!(self.flags.font(), Some(current) if current as u8 == font as u8),
+        }
+    }
+
+    /// Adds `effect` to the set, removing any effects it replaces.
+    pub fn insert(&mut self, effect: Effect) {
+        *self = self.set(effect, true);
+    }
+
+    /// Removes `effect` from the set.
+    pub fn remove(&mut self, effect: Effect) {
+        *self = self.set(effect, false);
+    }
+
+    /// Returns true if the set contains no effects
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.bold && self.flags.is_plain()
+    }
+
+    /// Returns the number of effects in the set
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns an iterator over the effects in the set, in the order they're declared in
+    /// [`Effect`].
+    pub fn iter(&self) -> EffectSetIter {
+        EffectSetIter {
+            set: *self,
+            index: 0,
+        }
+    }
+
+    #[must_use]
+    const fn set(mut self, effect: Effect, to: bool) -> Self {
+        use Effect::*;
+        match effect {
+            Bold => {
+                self.bold = to;
+            }
+            Dimmed => {
+                // This somewhat contorted construction is required because const fns can't take
+                // mutable refs as of Rust 1.81.
+                self.flags = self.flags.set_dimmed(to);
+            }
+            Italic => {
+                self.flags = self.flags.set_italic(to);
+            }
+            Underline => {
+                self.flags = self.flags.set_underline(to);
+            }
+            Blink => {
+                self.flags = self.flags.set_blink(to);
+            }
+            BlinkFast => {
+                self.flags = self.flags.set_blink_fast(to);
+            }
+            Reversed => {
+                self.flags = self.flags.set_reversed(to);
+            }
+            Hidden => {
+                self.flags = self.flags.set_hidden(to);
+            }
+            Strikethrough => {
+                self.flags = self.flags.set_strikethrough(to);
+            }
+            DoubleUnderline => {
+                self.flags = self.flags.set_double_underline(to);
+            }
+            CurlyUnderline => {
+                self.flags = self.flags.set_curly_underline(to);
+            }
+            DottedUnderline => {
+                self.flags = self.flags.set_dotted_underline(to);
+            }
+            DashedUnderline => {
+                self.flags = self.flags.set_dashed_underline(to);
+            }
+            Overline => {
+                self.flags = self.flags.set_overline(to);
+            }
+            Framed => {
+                self.flags = self.flags.set_framed(to);
+            }
+            Encircled => {
+                self.flags = self.flags.set_encircled(to);
+            }
+            Superscript => {
+                self.flags = self.flags.set_superscript(to);
+            }
+            Subscript => {
+                self.flags = self.flags.set_subscript(to);
+            }
+            Fraktur => {
+                self.flags = self.flags.set_fraktur(to);
+            }
+            Font(font) => {
+                if to {
+                    self.flags = self.flags.set_font(Some(font));
+                } else if let Some(current) = self.flags.font() {
+                    // Only remove the font if it's the one being removed
+                    if current as u8 == font as u8 {
+                        self.flags = self.flags.set_font(None);
+                    }
+                }
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Debug for EffectSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl IntoIterator for EffectSet {
+    type Item = Effect;
+    type IntoIter = EffectSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &EffectSet {
+    type Item = Effect;
+    type IntoIter = EffectSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Effect> for EffectSet {
+    fn from_iter<I: IntoIterator<Item = Effect>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Effect> for EffectSet {
+    fn extend<I: IntoIterator<Item = Effect>>(&mut self, iter: I) {
+        for effect in iter {
+            self.insert(effect);
+        }
+    }
+}
+
+/// An iterator over the effects in an [`EffectSet`], created by [`EffectSet::iter`]
+#[derive(Clone, Debug)]
+pub struct EffectSetIter {
+    set: EffectSet,
+    index: usize,
+}
+
+impl Iterator for EffectSetIter {
+    type Item = Effect;
+
+    fn next(&mut self) -> Option<Effect> {
+        while let Some(&effect) = FLAG_EFFECTS.get(self.index) {
+            self.index += 1;
+            if self.set.contains(effect) {
+                return Some(effect);
+            }
+        }
+        if self.index == FLAG_EFFECTS.len() {
+            self.index += 1;
+            if let Some(font) = self.set.flags.font() {
+                return Some(Effect::Font(font));
+            }
+        }
+        None
+    }
+}
+
+impl core::iter::FusedIterator for EffectSetIter {}
+
 impl Style {
     /// Create a new style to be applied later
     #[must_use]
@@ -155,8 +621,10 @@ impl Style {
         Self {
             fg: None,
             bg: None,
+            underline_color: None,
             bold: false,
             style_flags: StyleFlags::new(),
+            hyperlink: None,
         }
     }
 
@@ -229,6 +697,46 @@ impl Style {
         self
     }
 
+    /// Removes the underline color from the style. Note that this does not apply the default
+    /// underline color, but rather represents not changing the current underline color.
+    #[must_use]
+    pub const fn remove_underline_color(mut self) -> Self {
+        self.underline_color = None;
+        self
+    }
+
+    /// Turns the styled text into a clickable link to `url`, using the OSC 8 escape sequence in
+    /// the same way as [`OwoColorize::hyperlink`]. Characters which can't appear in a URL are
+    /// percent-encoded.
+    ///
+    /// The URL is borrowed for `'static` so that styles can still be copied freely, which suits
+    /// links that are part of a theme. For links to URLs built at runtime, use
+    /// [`OwoColorize::hyperlink`]. Links aren't included when a style is displayed as text or
+    /// serialized, as their syntax has no way to express them.
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, Style};
+    ///
+    /// let help = Style::new().blue().underline().hyperlink("https://docs.rs/owo-colors");
+    /// println!("See the {} for details", "documentation".style(help));
+    /// # assert_eq!(
+    /// #     "docs".style(help).to_string(),
+    /// #     "\x1b]8;;https://docs.rs/owo-colors\x1b\\\x1b[34;4mdocs\x1b[0m\x1b]8;;\x1b\\",
+    /// # );
+    /// ```
+    #[must_use]
+    pub const fn hyperlink(mut self, url: &'static str) -> Self {
+        self.hyperlink = Some(url);
+        self
+    }
+
+    /// Removes the link from the style.
+    #[must_use]
+    pub const fn remove_hyperlink(mut self) -> Self {
+        self.hyperlink = None;
+        self
+    }
+
     color_methods! {
         /// Change the foreground color to black
         /// Change the background color to black
@@ -315,42 +823,55 @@ impl Style {
         (hidden, set_hidden),
         /// Cross out the text
         (strikethrough, set_strikethrough),
+        /// Make the text double underlined, replacing any other kind of underline. Not all
+        /// terminals support this; those which don't may draw a plain underline or nothing.
+        (double_underline, set_double_underline),
+        /// Make the text underlined with a curly line, replacing any other kind of underline. Not
+        /// all terminals support this; those which don't may draw a plain underline or nothing.
+        (curly_underline, set_curly_underline),
+        /// Make the text underlined with a dotted line, replacing any other kind of underline. Not
+        /// all terminals support this; those which don't may draw a plain underline or nothing.
+        (dotted_underline, set_dotted_underline),
+        /// Make the text underlined with a dashed line, replacing any other kind of underline. Not
+        /// all terminals support this; those which don't may draw a plain underline or nothing.
+        (dashed_underline, set_dashed_underline),
+        /// Draw a line above the text
+        (overline, set_overline),
+        /// Draw a frame around the text, replacing [`encircled`](Style::encircled)
+        (framed, set_framed),
+        /// Draw a circle around the text, replacing [`framed`](Style::framed)
+        (encircled, set_encircled),
+        /// Make the text superscript, replacing [`subscript`](Style::subscript)
+        (superscript, set_superscript),
+        /// Make the text subscript, replacing [`superscript`](Style::superscript)
+        (subscript, set_subscript),
+        /// Display the text in a Fraktur (blackletter) font, replacing any other
+        /// [`font`](Style::font)
+        (fraktur, set_fraktur),
+    }
+
+    /// Display the text in an alternate font, or in the primary font with [`Font::Primary`]. This
+    /// replaces [`fraktur`](Style::fraktur) if it was set.
+    ///
+    /// Which fonts are available depends on the terminal, and most terminals ignore this.
+    ///
+    /// ```rust
+    /// use owo_colors::{Font, Style};
+    ///
+    /// let style = Style::new().font(Font::Alt3);
+    /// assert_eq!(style.style("text").to_string(), "\x1b[13mtext\x1b[0m");
+    /// ```
+    #[must_use]
+    pub const fn font(mut self, font: Font) -> Self {
+        self.style_flags = self.style_flags.set_font(Some(font));
+        self
     }
 
     #[must_use]
     const fn set_effect(mut self, effect: Effect, to: bool) -> Self {
-        use Effect::*;
-        match effect {
-            Bold => {
-                self.bold = to;
-            }
-            Dimmed => {
-                // This somewhat contorted construction is required because const fns can't take
-                // mutable refs as of Rust 1.81.
-                self.style_flags = self.style_flags.set_dimmed(to);
-            }
-            Italic => {
-                self.style_flags = self.style_flags.set_italic(to);
-            }
-            Underline => {
-                self.style_flags = self.style_flags.set_underline(to);
-            }
-            Blink => {
-                self.style_flags = self.style_flags.set_blink(to);
-            }
-            BlinkFast => {
-                self.style_flags = self.style_flags.set_blink_fast(to);
-            }
-            Reversed => {
-                self.style_flags = self.style_flags.set_reversed(to);
-            }
-            Hidden => {
-                self.style_flags = self.style_flags.set_hidden(to);
-            }
-            Strikethrough => {
-                self.style_flags = self.style_flags.set_strikethrough(to);
-            }
-        }
+        let effects = self.effect_set().set(effect, to);
+        self.bold = effects.bold;
+        self.style_flags = effects.flags;
         self
     }
 
@@ -428,6 +949,79 @@ impl Style {
         self
     }
 
+    /// Set the background color at runtime, along with a black or white foreground color which is
+    /// readable on top of it. See [`DynColors::readable_foreground`].
+    ///
+    /// If the background is [`AnsiColors::Default`], whose color isn't known, the foreground
+    /// color is left unchanged.
+    ///
+    /// ```rust
+    /// use owo_colors::{Rgb, Style};
+    ///
+    /// let style = Style::new().on_color_auto_fg(Rgb(255, 220, 0));
+    /// assert_eq!(style, Style::new().on_truecolor(255, 220, 0).truecolor(0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn on_color_auto_fg<Color: DynColor>(mut self, color: Color) -> Self {
+        let bg = color.get_dyncolors_bg();
+        self.bg = Some(bg);
+        if let Some(fg) = bg.readable_foreground() {
+            self.fg = Some(fg);
+        }
+        self
+    }
+
+    /// Set the background color at runtime, along with a black or white foreground color which is
+    /// readable on top of it when the ANSI colors are displayed using `palette`. See
+    /// [`DynColors::readable_foreground_with`].
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, Palette, Style};
+    ///
+    /// let palette = Palette::SOLARIZED_LIGHT;
+    /// let style = Style::new().on_color_auto_fg_with(AnsiColors::Default, &palette);
+    /// assert_eq!(style, Style::new().on_default_color().black());
+    /// ```
+    #[must_use]
+    pub fn on_color_auto_fg_with<Color: DynColor>(
+        mut self,
+        color: Color,
+        palette: &Palette,
+    ) -> Self {
+        let bg = color.get_dyncolors_bg();
+        self.bg = Some(bg);
+        self.fg = Some(bg.readable_foreground_with(palette));
+        self
+    }
+
+    /// Set the underline color at runtime. This only changes the color of the line, so it should
+    /// be combined with [`underline`](Style::underline) or another kind of underline. Terminals
+    /// which don't support underline colors draw the underline in the foreground color.
+    ///
+    /// [`AnsiColors::Default`] sets the underline back to the terminal's default, which is usually
+    /// the foreground color.
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, AnsiColors, Style};
+    ///
+    /// let squiggle = Style::new().curly_underline().underline_color(AnsiColors::Red);
+    /// println!("{}", "misspeled".style(squiggle));
+    /// # assert_eq!(squiggle.style("x").to_string(), "\x1b[58;5;1;4:3mx\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn underline_color<Color: DynColor>(mut self, color: Color) -> Self {
+        // Can't be const because `get_dyncolors_fg` is a trait method.
+        self.underline_color = Some(color.get_dyncolors_fg());
+        self
+    }
+
+    /// Sets the underline color to an RGB value. See [`Style::underline_color`].
+    #[must_use]
+    pub const fn underline_truecolor(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.underline_color = Some(DynColors::Rgb(r, g, b));
+        self
+    }
+
     /// Set the foreground color to a specific RGB value.
     #[must_use]
     pub const fn fg_rgb<const R: u8, const G: u8, const B: u8>(mut self) -> Self {
@@ -458,12 +1052,130 @@ impl Style {
         self
     }
 
+    /// Apply `other` on top of this style: colors set by `other` replace this style's, and the
+    /// effects of both are combined. Where both set effects which replace each other, such as
+    /// two kinds of underline, `other`'s effect is kept.
+    ///
+    /// This is the style displayed when text styled with `other` is nested in text styled with
+    /// this style. The same operation is available as `self | other`.
+    ///
+    /// ```rust
+    /// use owo_colors::Style;
+    ///
+    /// let base = Style::new().white().on_black().underline();
+    /// let highlight = Style::new().yellow().bold().curly_underline();
+    /// assert_eq!(
+    ///     base.patch(&highlight),
+    ///     Style::new().yellow().on_black().bold().curly_underline(),
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn patch(self, other: &Style) -> Self {
+        Self {
+            fg: if other.fg.is_some() {
+                other.fg
+            } else {
+                self.fg
+            },
+            bg: if other.bg.is_some() {
+                other.bg
+            } else {
+                self.bg
+            },
+            underline_color: if other.underline_color.is_some() {
+                other.underline_color
+            } else {
+                self.underline_color
+            },
+            bold: self.bold || other.bold,
+            style_flags: self.style_flags.patch(other.style_flags),
+            hyperlink: if other.hyperlink.is_some() {
+                other.hyperlink
+            } else {
+                self.hyperlink
+            },
+        }
+    }
+
+    /// Returns the foreground color set by the style, if any.
+    ///
+    /// ```rust
+    /// use owo_colors::{AnsiColors, DynColors, Style};
+    ///
+    /// let style = Style::new().red().on_black();
+    /// assert_eq!(style.get_fg(), Some(DynColors::Ansi(AnsiColors::Red)));
+    /// assert_eq!(Style::new().get_fg(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_fg(&self) -> Option<DynColors> {
+        self.fg
+    }
+
+    /// Returns the background color set by the style, if any.
+    #[must_use]
+    pub const fn get_bg(&self) -> Option<DynColors> {
+        self.bg
+    }
+
+    /// Returns the underline color set by the style, if any.
+    #[must_use]
+    pub const fn get_underline_color(&self) -> Option<DynColors> {
+        self.underline_color
+    }
+
+    /// Returns the URL the style links to, if any. See [`Style::hyperlink`].
+    #[must_use]
+    pub const fn get_hyperlink(&self) -> Option<&'static str> {
+        self.hyperlink
+    }
+
+    /// Returns true if the style applies `effect`. For [`Effect::Font`], the font has to match
+    /// too.
+    ///
+    /// ```rust
+    /// use owo_colors::{Effect, Style};
+    ///
+    /// let style = Style::new().bold().curly_underline();
+    /// assert!(style.has_effect(Effect::Bold));
+    /// assert!(style.has_effect(Effect::CurlyUnderline));
+    /// assert!(!style.has_effect(Effect::Underline));
+    /// ```
+    #[must_use]
+    pub const fn has_effect(&self, effect: Effect) -> bool {
+        self.effect_set().contains(effect)
+    }
+
+    /// Returns the effects applied by the style.
+    ///
+    /// ```rust
+    /// use owo_colors::{Effect, Font, Style};
+    ///
+    /// let style = Style::new().italic().bold().font(Font::Alt3);
+    /// assert_eq!(
+    ///     style.effect_set().iter().collect::<Vec<_>>(),
+    ///     [Effect::Bold, Effect::Italic, Effect::Font(Font::Alt3)],
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn effect_set(&self) -> EffectSet {
+        EffectSet {
+            bold: self.bold,
+            flags: self.style_flags,
+        }
+    }
+
     /// Returns true if the style does not apply any formatting.
     #[must_use]
     #[inline]
     pub const fn is_plain(&self) -> bool {
+        self.is_sgr_plain() && self.hyperlink.is_none()
+    }
+
+    /// Returns true if the style doesn't apply any colors or effects, ignoring its link.
+    pub(crate) const fn is_sgr_plain(&self) -> bool {
         let s = &self;
-        !(s.fg.is_some() || s.bg.is_some() || s.bold) && s.style_flags.is_plain()
+        !(s.fg.is_some() || s.bg.is_some() || s.underline_color.is_some() || s.bold)
+            && s.style_flags.is_plain()
     }
 
     /// Returns a formatter for the style's ANSI prefix.
@@ -510,10 +1222,14 @@ impl Style {
         let s = self;
         let format_less_important_effects = s.style_flags != StyleFlags::default();
         let format_effect = s.bold || format_less_important_effects;
-        let format_any = !self.is_plain();
+        let format_any = !self.is_sgr_plain();
 
         let mut semicolon = false;
 
+        if let Some(url) = s.hyperlink {
+            crate::hyperlink::fmt_link_start(f, url, None)?;
+        }
+
         if format_any {
             f.write_str("\x1b[")?;
         }
@@ -524,10 +1240,19 @@ impl Style {
         }
 
         if let Some(bg) = s.bg {
-            if s.fg.is_some() {
+            if semicolon {
                 f.write_str(";")?;
             }
             <DynColors as DynColor>::fmt_raw_ansi_bg(&bg, f)?;
+            semicolon = true;
+        }
+
+        if let Some(underline_color) = s.underline_color {
+            if semicolon {
+                f.write_str(";")?;
+            }
+            underline_color.fmt_raw_ansi_underline(f)?;
+            semicolon = true;
         }
 
         if format_effect {
@@ -567,6 +1292,24 @@ impl Style {
                     (reversed,      "7"),
                     (hidden,        "8"),
                     (strikethrough, "9"),
+                    (double_underline, "4:2"),
+                    (curly_underline,  "4:3"),
+                    (dotted_underline, "4:4"),
+                    (dashed_underline, "4:5"),
+                    (fraktur,          "20"),
+                    (framed,           "51"),
+                    (encircled,        "52"),
+                    (overline,         "53"),
+                    (superscript,      "73"),
+                    (subscript,        "74"),
+                }
+
+                if let Some(font) = s.style_flags.font() {
+                    if semicolon {
+                        f.write_str(";")?;
+                    }
+                    write!(f, "1{}", font.number())?;
+                    semicolon = true;
                 }
             }
         }
@@ -577,14 +1320,55 @@ impl Style {
         Ok(())
     }
 
-    /// Applies the ANSI-suffix for this style to the given formatter
+    /// Applies the ANSI-suffix for this style to the given formatter. This is a full reset, or
+    /// only turns off the attributes this style sets if granular resets are enabled with
+    /// [`set_reset_mode`](crate::set_reset_mode), followed by the end of the style's link.
     #[inline]
     pub fn fmt_suffix(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if !self.is_plain() {
+        if self.is_sgr_plain() {
+        } else if crate::resets::is_granular() {
+            self.fmt_granular_suffix(f)?;
+        } else {
             f.write_str("\x1b[0m")?;
         }
+        if self.hyperlink.is_some() {
+            f.write_str(crate::hyperlink::LINK_END)?;
+        }
         Ok(())
     }
+
+    /// Turns off each attribute this style sets, without affecting any others.
+    fn fmt_granular_suffix(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self;
+        let flags = &s.style_flags;
+        let resets = [
+            (s.fg.is_some(), "39"),
+            (s.bg.is_some(), "49"),
+            (s.underline_color.is_some(), "59"),
+            (s.bold || flags.dimmed(), "22"),
+            (flags.italic() || flags.fraktur(), "23"),
+            (flags.0 & UNDERLINE_MASK != 0, "24"),
+            (flags.blink() || flags.blink_fast(), "25"),
+            (flags.reversed(), "27"),
+            (flags.hidden(), "28"),
+            (flags.strikethrough(), "29"),
+            (flags.framed() || flags.encircled(), "54"),
+            (flags.overline(), "55"),
+            (flags.superscript() || flags.subscript(), "75"),
+            (flags.font().is_some(), "10"),
+        ];
+
+        let mut semicolon = false;
+        f.write_str("\x1b[")?;
+        for (_, code) in resets.iter().filter(|(set, _)| *set) {
+            if semicolon {
+                f.write_str(";")?;
+            }
+            f.write_str(code)?;
+            semicolon = true;
+        }
+        f.write_str("m")
+    }
 }
 
 /// Formatter for the prefix of a [`Style`].
@@ -621,6 +1405,93 @@ impl Default for Style {
     }
 }
 
+/// Combines two styles with [`Style::patch`].
+impl ops::BitOr for Style {
+    type Output = Style;
+
+    fn bitor(self, rhs: Style) -> Style {
+        self.patch(&rhs)
+    }
+}
+
+/// Returns what two styles have in common: the colors they both set to the same color, and the
+/// effects they both apply.
+///
+/// ```rust
+/// use owo_colors::Style;
+///
+/// let a = Style::new().red().on_white().bold().italic();
+/// let b = Style::new().red().on_black().italic();
+/// assert_eq!(a & b, Style::new().red().italic());
+/// ```
+impl ops::BitAnd for Style {
+    type Output = Style;
+
+    fn bitand(self, rhs: Style) -> Style {
+        let common = |a: Option<DynColors>, b: Option<DynColors>| if a == b { a } else { None };
+        Style {
+            fg: common(self.fg, rhs.fg),
+            bg: common(self.bg, rhs.bg),
+            underline_color: common(self.underline_color, rhs.underline_color),
+            bold: self.bold && rhs.bold,
+            style_flags: self.style_flags.intersect(rhs.style_flags),
+            hyperlink: if self.hyperlink == rhs.hyperlink {
+                self.hyperlink
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Returns what the first style sets that the second doesn't: the colors which are different in
+/// the second style, and the effects it doesn't apply.
+///
+/// ```rust
+/// use owo_colors::Style;
+///
+/// let a = Style::new().red().on_white().bold().italic();
+/// let b = Style::new().red().on_black().italic();
+/// assert_eq!(a - b, Style::new().on_white().bold());
+/// ```
+impl ops::Sub for Style {
+    type Output = Style;
+
+    fn sub(self, rhs: Style) -> Style {
+        let different = |a: Option<DynColors>, b: Option<DynColors>| if a != b { a } else { None };
+        Style {
+            fg: different(self.fg, rhs.fg),
+            bg: different(self.bg, rhs.bg),
+            underline_color: different(self.underline_color, rhs.underline_color),
+            bold: self.bold && !rhs.bold,
+            style_flags: self.style_flags.difference(rhs.style_flags),
+            hyperlink: if self.hyperlink != rhs.hyperlink {
+                self.hyperlink
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Applies an effect to the style, the same as [`Style::effect`].
+impl ops::BitOr<Effect> for Style {
+    type Output = Style;
+
+    fn bitor(self, rhs: Effect) -> Style {
+        self.effect(rhs)
+    }
+}
+
+/// Removes an effect from the style, the same as [`Style::remove_effect`].
+impl ops::Sub<Effect> for Style {
+    type Output = Style;
+
+    fn sub(self, rhs: Effect) -> Style {
+        self.remove_effect(rhs)
+    }
+}
+
 /// Helper to create [`Style`]s more ergonomically
 pub const fn style() -> Style {
     Style::new()
@@ -655,6 +1526,11 @@ macro_rules! impl_fmt {
             impl<T: $trait> $trait for Styled<T> {
                 #[allow(unused_assignments)]
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    #[cfg(feature = "std")]
+                    if let Some(result) = crate::style_stack::fmt_styled(self, f, <T as $trait>::fmt) {
+                        return result;
+                    }
+
                     self.style.fmt_prefix(f)?;
                     <T as $trait>::fmt(&self.target, f)?;
                     self.style.fmt_suffix(f)
@@ -683,8 +1559,14 @@ mod tests {
 
     #[test]
     fn size_of() {
+        // Colors and effects take 20 bytes, followed by the link's pointer and length
         let size = std::mem::size_of::<Style>();
-        assert_eq!(size, 10, "size of Style should be 10 bytes");
+        let expected = if cfg!(target_pointer_width = "64") {
+            40
+        } else {
+            28
+        };
+        assert_eq!(size, expected, "size of Style should be {expected} bytes");
     }
 
     #[test]
@@ -724,6 +1606,245 @@ mod tests {
         assert_eq!(&s2, "\u{1b}[4;9mTEST\u{1b}[0m");
     }
 
+    #[test]
+    fn test_underline_styles() {
+        let style = Style::new().curly_underline();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[4:3mTEST\u{1b}[0m"
+        );
+
+        // Only the last kind of underline applies
+        let style = Style::new()
+            .underline()
+            .dotted_underline()
+            .dashed_underline();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[4:5mTEST\u{1b}[0m"
+        );
+
+        let style = Style::new()
+            .red()
+            .effects(&[Effect::DoubleUnderline, Effect::Italic]);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[31;3;4:2mTEST\u{1b}[0m"
+        );
+        assert_eq!(
+            style.remove_effect(Effect::DoubleUnderline),
+            Style::new().red().italic()
+        );
+    }
+
+    #[test]
+    fn test_extra_effects() {
+        let style = Style::new().overline().framed().superscript();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[51;53;73mTEST\u{1b}[0m"
+        );
+
+        // Framed and encircled, superscript and subscript, and fonts exclude each other
+        let style = style.encircled().subscript().font(Font::Alt2).fraktur();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[20;52;53;74mTEST\u{1b}[0m"
+        );
+        let style = style.font(Font::Primary);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[52;53;74;10mTEST\u{1b}[0m"
+        );
+
+        let style = Style::new().effects(&[Effect::Font(Font::Alt9), Effect::Italic]);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[3;19mTEST\u{1b}[0m"
+        );
+        assert_eq!(style.remove_effect(Effect::Font(Font::Alt8)), style);
+        assert_eq!(
+            style.remove_effect(Effect::Font(Font::Alt9)),
+            Style::new().italic()
+        );
+    }
+
+    #[test]
+    fn test_font() {
+        for (number, font) in Font::ALL.into_iter().enumerate() {
+            assert_eq!(font.number() as usize, number);
+            assert_eq!(Font::new(number as u8), Some(font));
+        }
+        assert_eq!(Font::new(10), None);
+        assert_eq!(Font::new(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_granular_suffix() {
+        struct GranularSuffix(Style);
+
+        impl fmt::Display for GranularSuffix {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_granular_suffix(f)
+            }
+        }
+
+        let style = Style::new().red().on_blue().bold().dimmed().italic();
+        assert_eq!(GranularSuffix(style).to_string(), "\u{1b}[39;49;22;23m");
+
+        let style = Style::new()
+            .curly_underline()
+            .underline_color(AnsiColors::Red)
+            .blink_fast()
+            .reversed()
+            .hidden()
+            .strikethrough()
+            .encircled()
+            .overline()
+            .subscript()
+            .font(Font::Alt4);
+        assert_eq!(
+            GranularSuffix(style).to_string(),
+            "\u{1b}[59;24;25;27;28;29;54;55;75;10m"
+        );
+    }
+
+    #[test]
+    fn test_underline_color() {
+        let style = Style::new()
+            .underline()
+            .underline_color(AnsiColors::BrightRed);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[58;5;9;4mTEST\u{1b}[0m"
+        );
+
+        let style = Style::new()
+            .red()
+            .on_white()
+            .bold()
+            .underline_color(crate::XtermColors::FlushOrange);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[31;47;58;5;208;1mTEST\u{1b}[0m"
+        );
+
+        let style = Style::new().underline_truecolor(1, 2, 3).dashed_underline();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[58;2;1;2;3;4:5mTEST\u{1b}[0m"
+        );
+
+        let style = Style::new().underline_color(AnsiColors::Default);
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[59mTEST\u{1b}[0m"
+        );
+        assert!(style.remove_underline_color().is_plain());
+    }
+
+    #[test]
+    fn test_bg_then_effect() {
+        let style = Style::new().on_blue().bold();
+        assert_eq!(
+            format!("{}", style.style("TEST")),
+            "\u{1b}[44;1mTEST\u{1b}[0m"
+        );
+    }
+
+    #[test]
+    fn test_accessors() {
+        use Effect::{Bold, Dimmed, Encircled, Fraktur, Framed, Subscript, Superscript};
+
+        let style = Style::new()
+            .truecolor(1, 2, 3)
+            .on_blue()
+            .underline_color(AnsiColors::Red)
+            .dimmed()
+            .bold()
+            .framed()
+            .font(Font::Primary);
+        assert_eq!(style.get_fg(), Some(DynColors::Rgb(1, 2, 3)));
+        assert_eq!(style.get_bg(), Some(DynColors::Ansi(AnsiColors::Blue)));
+        assert_eq!(
+            style.get_underline_color(),
+            Some(DynColors::Ansi(AnsiColors::Red))
+        );
+        assert!(style.has_effect(Effect::Font(Font::Primary)));
+        assert!(!style.has_effect(Effect::Font(Font::Alt1)));
+        assert!(!style.has_effect(Encircled));
+
+        let effects = style.effect_set();
+        assert_eq!(effects.len(), 4);
+        assert_eq!(
+            effects.into_iter().collect::<Vec<_>>(),
+            [Bold, Dimmed, Framed, Effect::Font(Font::Primary)]
+        );
+        assert_eq!(
+            format!("{:?}", effects),
+            "{Bold, Dimmed, Framed, Font(Primary)}"
+        );
+        assert_eq!(
+            Style::new()
+                .effects(&[Bold, Dimmed, Framed, Effect::Font(Font::Primary)])
+                .effect_set(),
+            effects
+        );
+
+        let mut effects = EffectSet::new();
+        assert!(effects.is_empty());
+        effects.extend([Fraktur, Superscript, Subscript]);
+        assert_eq!(effects.iter().collect::<Vec<_>>(), [Subscript, Fraktur]);
+        effects.insert(Effect::Font(Font::Alt9));
+        effects.remove(Subscript);
+        assert_eq!(
+            effects.iter().collect::<Vec<_>>(),
+            [Effect::Font(Font::Alt9)]
+        );
+    }
+
+    #[test]
+    fn test_ops() {
+        use Effect::Bold;
+
+        let a = Style::new()
+            .red()
+            .on_white()
+            .bold()
+            .underline()
+            .font(Font::Alt2);
+        let b = Style::new()
+            .blue()
+            .on_white()
+            .double_underline()
+            .italic()
+            .font(Font::Alt2);
+
+        assert_eq!(
+            a | b,
+            Style::new()
+                .blue()
+                .on_white()
+                .bold()
+                .double_underline()
+                .italic()
+                .font(Font::Alt2)
+        );
+        assert_eq!(a & b, Style::new().on_white().font(Font::Alt2));
+        assert_eq!(a - b, Style::new().red().bold().underline());
+        assert_eq!(b - a, Style::new().blue().double_underline().italic());
+        assert_eq!(a - a, Style::new());
+
+        assert_eq!(
+            Style::new() | Bold | Effect::Font(Font::Alt3),
+            Style::new().bold().font(Font::Alt3)
+        );
+        assert_eq!(
+            a - Bold - Effect::Font(Font::Alt2),
+            Style::new().red().on_white().underline()
+        );
+    }
+
     #[test]
     fn test_color() {
         let style = Style::new()
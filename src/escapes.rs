@@ -0,0 +1,89 @@
+//! Recognizing terminal escape sequences in text that is being written.
+
+/// Tracks whether the characters of a stream are part of an escape sequence, one character at a
+/// time, so that it works across `fmt::Write::write_str` calls.
+///
+/// Recognizes CSI sequences (`ESC [ ... final`), string sequences terminated by BEL or ST (OSC,
+/// DCS, SOS, PM and APC), and other two-character or `nF` escape sequences.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct EscapeScanner {
+    state: State,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    /// After ESC
+    Escape,
+    /// After ESC and one or more intermediate bytes
+    EscapeIntermediate,
+    /// Inside a control sequence (after ESC [)
+    Csi,
+    /// Inside a control string (OSC, DCS, SOS, PM, APC)
+    String,
+    /// After ESC inside a control string, which might be the start of ST (ESC \)
+    StringEscape,
+}
+
+impl EscapeScanner {
+    /// Returns true if the scanner is not inside an escape sequence.
+    pub(crate) fn is_ground(&self) -> bool {
+        self.state == State::Ground
+    }
+
+    /// Feeds the next character of the stream, returning true if it is part of an escape sequence.
+    pub(crate) fn feed(&mut self, c: char) -> bool {
+        self.state = match (self.state, c) {
+            (State::Ground, '\x1b') => State::Escape,
+            (State::Ground, '\u{9b}') => State::Csi,
+            (State::Ground, '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}') => State::String,
+            (State::Ground, _) => return false,
+
+            (State::Escape, '[') => State::Csi,
+            (State::Escape, ']' | 'P' | 'X' | '^' | '_') => State::String,
+            (State::Escape | State::EscapeIntermediate, '\x20'..='\x2f') => {
+                State::EscapeIntermediate
+            }
+            (State::Escape | State::EscapeIntermediate, _) => State::Ground,
+
+            (State::Csi, '\x40'..='\x7e') => State::Ground,
+            (State::Csi, _) => State::Csi,
+
+            (State::String, '\x07' | '\u{9c}') => State::Ground,
+            (State::String, '\x1b') => State::StringEscape,
+            (State::String, _) => State::String,
+
+            (State::StringEscape, '\\') => State::Ground,
+            // Any other escape sequence also terminates the string
+            (State::StringEscape, _) => {
+                self.state = State::Escape;
+                return self.feed(c);
+            }
+        };
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visible(s: &str) -> String {
+        let mut scanner = EscapeScanner::default();
+        s.chars().filter(|&c| !scanner.feed(c)).collect()
+    }
+
+    #[test]
+    fn test_scanner() {
+        assert_eq!(visible("plain"), "plain");
+        assert_eq!(visible("\x1b[1;38;2;1;2;3mbold\x1b[0m"), "bold");
+        assert_eq!(
+            visible("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"),
+            "link"
+        );
+        assert_eq!(visible("\x1b(Bcharset\x1b7saved"), "charsetsaved");
+        assert_eq!(visible("\x1b]0;title\x1b[31mred"), "red");
+        assert_eq!(visible("\u{9b}31mred\u{9b}0m"), "red");
+    }
+}
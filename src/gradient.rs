@@ -0,0 +1,393 @@
+use crate::colors::oklab::Oklab;
+use crate::escapes::EscapeScanner;
+use crate::{DynColor, DynColors, Rgb};
+
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(doc)]
+use crate::OwoColorize;
+
+/// A display wrapper which colors each character of the wrapped value along a gradient.
+/// Recommended to be constructed using [`OwoColorize::gradient`],
+/// [`OwoColorize::gradient_stops`] or their background equivalents.
+///
+/// The gradient runs from the first to the last column of the widest line, so that columns of
+/// multi-line text line up. Colors are interpolated in the
+/// [OKLab](https://bottosson.github.io/posts/oklab/) color space, which gives evenly spaced
+/// steps without the muddy middle of a gradient interpolated in RGB.
+///
+/// Combining marks, variation selectors and characters joined by a zero width joiner keep the
+/// color of the character they are attached to. Escape sequences in the wrapped value's output
+/// (for example from [`OwoColorize::bold`]) are passed through unchanged.
+///
+/// The gradient depends on the width of the whole output, so with the `alloc` feature the wrapped
+/// value is formatted once into a buffer before it's colored. Without it, the value is formatted
+/// twice, once to measure it and once to color it, so its [`Display`](fmt::Display)
+/// implementation must write the same text both times.
+///
+/// Formatting parameters such as width and padding are not forwarded to the wrapped value.
+pub struct GradientDisplay<'a, T: ?Sized> {
+    inner: &'a T,
+    stops: Stops<'a>,
+    background: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Stops<'a> {
+    Pair([Rgb; 2]),
+    Slice(&'a [Rgb]),
+}
+
+impl<'a, T: ?Sized> GradientDisplay<'a, T> {
+    pub(crate) fn new(inner: &'a T, start: Rgb, end: Rgb, background: bool) -> Self {
+        Self {
+            inner,
+            stops: Stops::Pair([start, end]),
+            background,
+        }
+    }
+
+    pub(crate) fn with_stops(inner: &'a T, stops: &'a [Rgb], background: bool) -> Self {
+        Self {
+            inner,
+            stops: Stops::Slice(stops),
+            background,
+        }
+    }
+
+    fn stops(&self) -> &[Rgb] {
+        match &self.stops {
+            Stops::Pair(pair) => pair,
+            Stops::Slice(slice) => slice,
+        }
+    }
+}
+
+/// Returns the color `t` (from 0 to 1) of the way along evenly spaced stops.
+fn color_at(stops: &[Rgb], t: f32) -> Rgb {
+    match stops {
+        [] => unreachable!("gradients without stops are displayed uncolored"),
+        [only] => *only,
+        _ => {
+            let position = t * (stops.len() - 1) as f32;
+            let index = (position as usize).min(stops.len() - 2);
+            let (start, end) = (stops[index], stops[index + 1]);
+            Oklab::from_rgb(start)
+                .mix(Oklab::from_rgb(end), position - index as f32)
+                .to_rgb()
+        }
+    }
+}
+
+/// Returns true if `c` is displayed as part of the preceding character rather than on its own.
+///
+/// This is an approximation of extended grapheme clusters which covers the common combining
+/// marks, variation selectors, emoji modifiers and tag characters.
+pub(crate) fn extends_grapheme(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036f}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{0591}'..='\u{05bd}'
+            | '\u{0610}'..='\u{061a}'
+            | '\u{064b}'..='\u{065f}'
+            | '\u{0900}'..='\u{0903}'
+            | '\u{093a}'..='\u{094f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{200c}'..='\u{200d}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{fe20}'..='\u{fe2f}'
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            | '\u{e0020}'..='\u{e007f}'
+            | '\u{e0100}'..='\u{e01ef}'
+    )
+}
+
+/// Splits written text into lines and graphemes, skipping escape sequences.
+#[derive(Default)]
+struct Columns {
+    escapes: EscapeScanner,
+    column: usize,
+    /// Whether the previous character was a zero width joiner
+    joined: bool,
+}
+
+/// How a character fits into the current line.
+enum Segment {
+    Escape,
+    Newline,
+    /// A character which continues the previous grapheme
+    Continuation,
+    /// The first character of a grapheme in the given column
+    Grapheme(usize),
+}
+
+impl Columns {
+    fn next(&mut self, c: char) -> Segment {
+        if self.escapes.feed(c) {
+            return Segment::Escape;
+        }
+
+        let joined = core::mem::replace(&mut self.joined, c == '\u{200d}');
+        if c == '\n' {
+            self.column = 0;
+            Segment::Newline
+        } else if (joined || extends_grapheme(c)) && self.column > 0 {
+            Segment::Continuation
+        } else {
+            self.column += 1;
+            Segment::Grapheme(self.column - 1)
+        }
+    }
+}
+
+/// Measures the widest line, in graphemes.
+#[derive(Default)]
+struct Measure {
+    columns: Columns,
+    widest: usize,
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if let Segment::Grapheme(column) = self.columns.next(c) {
+                self.widest = self.widest.max(column + 1);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes text to a formatter, setting the color of each grapheme.
+struct Paint<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    stops: &'a [Rgb],
+    widest: usize,
+    background: bool,
+    columns: Columns,
+    /// The color currently set, if any
+    current: Option<DynColors>,
+}
+
+impl Paint<'_, '_> {
+    fn set_color(&mut self, column: usize) -> fmt::Result {
+        let t = if self.widest > 1 {
+            column as f32 / (self.widest - 1) as f32
+        } else {
+            0.0
+        };
+        let Rgb(r, g, b) = color_at(self.stops, t);
+        let color = DynColors::Rgb(r, g, b);
+
+        // Neighboring characters often end up with the same color once downgraded
+        #[cfg(feature = "supports-colors")]
+        let color = crate::supports_colors::downgrade(color).unwrap_or(color);
+
+        if self.current != Some(color) {
+            self.current = Some(color);
+            if self.background {
+                color.fmt_ansi_bg(self.f)?;
+            } else {
+                color.fmt_ansi_fg(self.f)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) -> fmt::Result {
+        if self.current.take().is_some() {
+            self.f.write_str(if self.background {
+                "\x1b[49m"
+            } else {
+                "\x1b[39m"
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for Paint<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.columns.next(c) {
+                Segment::Escape => {
+                    // The wrapped value might have reset the color
+                    if self.columns.escapes.is_ground() {
+                        self.current = None;
+                    }
+                }
+                // Don't let the background bleed past the end of the line
+                Segment::Newline => self.reset()?,
+                Segment::Continuation => {}
+                Segment::Grapheme(column) => {
+                    if self.background || !c.is_whitespace() {
+                        self.set_color(column)?;
+                    }
+                }
+            }
+            self.f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for GradientDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stops = self.stops();
+        if stops.is_empty() {
+            return write!(f, "{}", self.inner);
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut output = alloc::string::String::new();
+            write!(output, "{}", self.inner)?;
+            paint(f, stops, self.background, output.as_str())
+        }
+        #[cfg(not(feature = "alloc"))]
+        paint(f, stops, self.background, self.inner)
+    }
+}
+
+/// Measures `inner`, then writes it with each grapheme colored along the gradient.
+fn paint<T: ?Sized + fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    stops: &[Rgb],
+    background: bool,
+    inner: &T,
+) -> fmt::Result {
+    let mut measure = Measure::default();
+    write!(measure, "{}", inner)?;
+
+    let mut paint = Paint {
+        f,
+        stops,
+        widest: measure.widest,
+        background,
+        columns: Columns::default(),
+        current: None,
+    };
+    write!(paint, "{}", inner)?;
+    paint.reset()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OwoColorize;
+
+    #[test]
+    fn test_gradient() {
+        let black_to_white = "abc".gradient(Rgb(0, 0, 0), Rgb(255, 255, 255));
+        assert_eq!(
+            black_to_white.to_string(),
+            "\x1b[38;2;0;0;0ma\x1b[38;2;99;99;99mb\x1b[38;2;255;255;255mc\x1b[39m"
+        );
+
+        assert_eq!(
+            "ab".on_gradient(Rgb(255, 0, 0), Rgb(255, 0, 0)).to_string(),
+            "\x1b[48;2;255;0;0mab\x1b[49m"
+        );
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let stops = [Rgb(255, 0, 0), Rgb(0, 255, 0), Rgb(0, 0, 255)];
+        let rendered = "abcde".gradient_stops(&stops).to_string();
+        assert!(rendered.starts_with("\x1b[38;2;255;0;0ma"));
+        assert!(rendered.contains("\x1b[38;2;0;255;0mc"));
+        assert!(rendered.ends_with("\x1b[38;2;0;0;255me\x1b[39m"));
+
+        assert_eq!("plain".gradient_stops(&[]).to_string(), "plain");
+        assert_eq!(
+            "ab".gradient_stops(&stops[..1]).to_string(),
+            "\x1b[38;2;255;0;0mab\x1b[39m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_gradient_formats_once() {
+        use core::cell::Cell;
+
+        // Writes a longer line each time it's displayed
+        struct Growing(Cell<usize>);
+
+        impl fmt::Display for Growing {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.set(self.0.get() + 1);
+                for _ in 0..self.0.get() {
+                    f.write_char('a')?;
+                }
+                Ok(())
+            }
+        }
+
+        let growing = Growing(Cell::new(0));
+        let rendered = growing
+            .gradient(Rgb(0, 0, 0), Rgb(255, 255, 255))
+            .to_string();
+        assert_eq!(growing.0.get(), 1);
+        assert_eq!(rendered, "\x1b[38;2;0;0;0ma\x1b[39m");
+    }
+
+    #[test]
+    fn test_gradient_lines() {
+        // Columns line up across lines, whitespace isn't colored and the color is reset before
+        // each newline
+        let rendered = "a b\nab"
+            .gradient(Rgb(0, 0, 0), Rgb(255, 255, 255))
+            .to_string();
+        assert_eq!(
+            rendered,
+            "\x1b[38;2;0;0;0ma \x1b[38;2;255;255;255mb\x1b[39m\n\
+             \x1b[38;2;0;0;0ma\x1b[38;2;99;99;99mb\x1b[39m"
+        );
+    }
+
+    #[test]
+    fn test_gradient_graphemes() {
+        let red_to_blue = |s: &'static str| s.gradient(Rgb(255, 0, 0), Rgb(0, 0, 255)).to_string();
+
+        // The accent is attached to the "e"
+        assert_eq!(
+            red_to_blue("e\u{301}a"),
+            "\x1b[38;2;255;0;0me\u{301}\x1b[38;2;0;0;255ma\x1b[39m"
+        );
+        // Joined emoji count as a single character
+        assert_eq!(
+            red_to_blue("👩\u{200d}🔬x"),
+            "\x1b[38;2;255;0;0m👩\u{200d}🔬\x1b[38;2;0;0;255mx\x1b[39m"
+        );
+    }
+
+    #[test]
+    fn test_gradient_escapes() {
+        let bold = "ab".bold();
+        assert_eq!(
+            bold.gradient(Rgb(255, 0, 0), Rgb(0, 0, 255)).to_string(),
+            "\x1b[1m\x1b[38;2;255;0;0ma\x1b[38;2;0;0;255mb\x1b[0m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "supports-colors")]
+    fn test_gradient_downgraded() {
+        use crate::ColorDepth;
+        use crate::supports_colors::with_color_depth;
+
+        // Repeated colors are only written once after downgrading
+        let rendered = with_color_depth(ColorDepth::Ansi16, || {
+            "abcd"
+                .gradient(Rgb(250, 0, 0), Rgb(255, 20, 20))
+                .to_string()
+        });
+        assert_eq!(rendered, "\x1b[91mabcd\x1b[39m");
+    }
+}
@@ -0,0 +1,334 @@
+//! Clickable links using the OSC 8 escape sequence.
+use core::fmt::{self, Write};
+
+#[cfg(doc)]
+use crate::OwoColorize;
+#[cfg(feature = "supports-colors")]
+use crate::Stream;
+
+/// A display wrapper which turns the wrapped value into a clickable link to a URL, in terminals
+/// which support [OSC 8 hyperlinks](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda).
+/// Recommended to be constructed using [`OwoColorize::hyperlink`].
+///
+/// Links can be combined with colors and effects by styling either the link or the value inside
+/// it:
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, Style};
+///
+/// let url = "https://docs.rs/owo-colors";
+/// println!("{}", "docs".blue().underline().hyperlink(url));
+/// println!("{}", "docs".hyperlink(url).style(Style::new().blue().underline()));
+/// # assert_eq!(
+/// #     "docs".bold().hyperlink(url).to_string(),
+/// #     "\x1b]8;;https://docs.rs/owo-colors\x1b\\\x1b[1mdocs\x1b[0m\x1b]8;;\x1b\\",
+/// # );
+/// ```
+///
+/// Characters which can't appear in a URL, such as spaces, control characters and non-ASCII
+/// characters, are percent-encoded.
+pub struct HyperlinkDisplay<'a, T: ?Sized> {
+    inner: &'a T,
+    url: &'a str,
+    id: Option<&'a str>,
+    #[cfg(feature = "supports-colors")]
+    fallback: Option<(Stream, LinkFallback)>,
+}
+
+/// What a [`HyperlinkDisplay`] displays in place of the link, if the stream doesn't support
+/// styling. See [`HyperlinkDisplay::if_supported`].
+#[cfg(feature = "supports-colors")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinkFallback {
+    /// Only display the wrapped value, dropping the URL
+    Text,
+    /// Display the wrapped value followed by the URL in parentheses, as in `docs (https://...)`
+    TextAndUrl,
+}
+
+impl<'a, T: ?Sized> HyperlinkDisplay<'a, T> {
+    /// Create a new [`HyperlinkDisplay`] which links `thing` to `url`.
+    ///
+    /// This is a const function: in non-const contexts, [`OwoColorize::hyperlink`] may be more
+    /// convenient.
+    pub const fn new(thing: &'a T, url: &'a str) -> Self {
+        Self {
+            inner: thing,
+            url,
+            id: None,
+            #[cfg(feature = "supports-colors")]
+            fallback: None,
+        }
+    }
+
+    /// Set the `id` parameter of the link. Terminals treat separate links to the same URL with
+    /// the same id as one link, for example to highlight all of them on hover when a link is
+    /// split across several lines.
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// let link = "docs".hyperlink("https://docs.rs").id("docs-1");
+    /// assert_eq!(
+    ///     link.to_string(),
+    ///     "\x1b]8;id=docs-1;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Only display the link if `stream` supports styling, in the same way as
+    /// [`OwoColorize::if_supports_color`], and display `fallback` otherwise.
+    ///
+    /// ```rust
+    /// use owo_colors::{LinkFallback, OwoColorize, Stream, with_override};
+    ///
+    /// let link = "docs"
+    ///     .hyperlink("https://docs.rs")
+    ///     .if_supported(Stream::Stdout, LinkFallback::TextAndUrl);
+    ///
+    /// with_override(false, || {
+    ///     assert_eq!(link.to_string(), "docs (https://docs.rs)");
+    /// });
+    /// ```
+    #[cfg(feature = "supports-colors")]
+    #[must_use]
+    pub fn if_supported(mut self, stream: impl Into<Stream>, fallback: LinkFallback) -> Self {
+        self.fallback = Some((stream.into(), fallback));
+        self
+    }
+}
+
+/// The escape sequence which ends a link.
+pub(crate) const LINK_END: &str = "\x1b]8;;\x1b\\";
+
+/// Writes the escape sequence which starts a link to `url`, with the given `id` parameter.
+pub(crate) fn fmt_link_start(
+    f: &mut fmt::Formatter<'_>,
+    url: &str,
+    id: Option<&str>,
+) -> fmt::Result {
+    f.write_str("\x1b]8;")?;
+    if let Some(id) = id {
+        f.write_str("id=")?;
+        write_encoded(f, id, |c| c == ':' || c == ';')?;
+    }
+    f.write_char(';')?;
+    write_encoded(f, url, |_| false)?;
+    f.write_str("\x1b\\")
+}
+
+/// Writes `s`, percent-encoding characters which aren't printable ASCII or for which `reserved`
+/// returns true, so that they can't end the escape sequence.
+fn write_encoded(
+    f: &mut fmt::Formatter<'_>,
+    s: &str,
+    reserved: impl Fn(char) -> bool,
+) -> fmt::Result {
+    let mut buf = [0; 4];
+    for c in s.chars() {
+        if c.is_ascii_graphic() && !reserved(c) {
+            f.write_char(c)?;
+        } else {
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                write!(f, "%{:02X}", byte)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_fmt_for_hyperlink {
+    ($($trait:path),* $(,)?) => {
+        $(
+            impl<'a, T: ?Sized + $trait> $trait for HyperlinkDisplay<'a, T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    #[cfg(feature = "supports-colors")]
+                    if let Some((stream, fallback)) = self.fallback {
+                        if crate::supports_colors::color_depth(stream).is_none() {
+                            <T as $trait>::fmt(self.inner, f)?;
+                            if fallback == LinkFallback::TextAndUrl {
+                                write!(f, " ({})", self.url)?;
+                            }
+                            return Ok(());
+                        }
+                    }
+
+                    fmt_link_start(f, self.url, self.id)?;
+                    <T as $trait>::fmt(self.inner, f)?;
+                    f.write_str(LINK_END)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_for_hyperlink! {
+    fmt::Display,
+    fmt::Debug,
+    fmt::UpperHex,
+    fmt::LowerHex,
+    fmt::Binary,
+    fmt::UpperExp,
+    fmt::LowerExp,
+    fmt::Octal,
+    fmt::Pointer,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{OwoColorize, Style};
+    use std::{format, string::ToString};
+
+    #[test]
+    fn test_hyperlink() {
+        assert_eq!(
+            "docs".hyperlink("https://docs.rs").to_string(),
+            "\x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            format!("{:x}", 255.hyperlink("https://example.com/?q=a b")),
+            "\x1b]8;;https://example.com/?q=a%20b\x1b\\ff\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            "x".hyperlink("file:///tmp/\x1b]ö").id("a;b").to_string(),
+            "\x1b]8;id=a%3Bb;file:///tmp/%1B]%C3%B6\x1b\\x\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_hyperlink_styled() {
+        let style = Style::new().red().underline();
+        assert_eq!(
+            "docs".hyperlink("https://docs.rs").style(style).to_string(),
+            "\x1b[31;4m\x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_style_hyperlink() {
+        const A: &str = "https://a.example";
+        const B: &str = "https://b.example";
+        const START_A: &str = "\x1b]8;;https://a.example\x1b\\";
+        const END: &str = "\x1b]8;;\x1b\\";
+
+        let link = Style::new().hyperlink(A);
+        assert!(!link.is_plain());
+        assert_eq!(link.get_hyperlink(), Some(A));
+        assert!(link.remove_hyperlink().is_plain());
+        assert_eq!(link.style("a").to_string(), format!("{START_A}a{END}"));
+        assert_eq!(
+            link.bold().style("a").to_string(),
+            format!("{START_A}\x1b[1ma\x1b[0m{END}")
+        );
+        assert_eq!(
+            Style::new().hyperlink("x y").style("a").to_string(),
+            format!("\x1b]8;;x%20y\x1b\\a{END}")
+        );
+
+        assert_eq!(Style::new().red() | link, Style::new().red().hyperlink(A));
+        assert_eq!(link | Style::new().hyperlink(B), Style::new().hyperlink(B));
+        assert_eq!(link.red() & link.bold(), link);
+        assert_eq!(
+            link.red() & Style::new().red().hyperlink(B),
+            Style::new().red()
+        );
+        assert_eq!(link.red() - Style::new().red(), link);
+        assert_eq!(link.red() - link, Style::new().red());
+
+        // Links can't be written as text
+        assert_eq!(link.to_string(), "normal");
+        assert_eq!(link.bold().to_string(), "bold");
+    }
+
+    #[test]
+    fn test_style_hyperlink_transitions() {
+        use crate::StyledList;
+
+        const A: &str = "https://a.example";
+        const START_A: &str = "\x1b]8;;https://a.example\x1b\\";
+        const START_B: &str = "\x1b]8;;https://b.example\x1b\\";
+        const END: &str = "\x1b]8;;\x1b\\";
+
+        let link = Style::new().hyperlink(A);
+        let other = Style::new().hyperlink("https://b.example");
+
+        // The link continues across changes of style, and is only started again if it changes
+        let list = StyledList::from([
+            link.style("a"),
+            link.bold().style("b"),
+            other.bold().style("c"),
+        ]);
+        assert_eq!(
+            list.to_string(),
+            format!("{START_A}a\x1b[1mb{START_B}c\x1b[0m{END}")
+        );
+
+        // Ending a link needs a reset
+        let list = StyledList::from([link.red().style("a"), Style::new().red().style("b")]);
+        assert_eq!(
+            list.to_string(),
+            format!("{START_A}\x1b[31ma\x1b[0m{END}\x1b[31mb\x1b[0m")
+        );
+        let list = StyledList::from([link.style("a"), Style::new().style("b")]);
+        assert_eq!(list.to_string(), format!("{START_A}a{END}b"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_style_hyperlink_nested() {
+        use crate::with_style_stack;
+
+        const START: &str = "\x1b]8;;https://a.example\x1b\\";
+        const END: &str = "\x1b]8;;\x1b\\";
+
+        let link = Style::new().hyperlink("https://a.example");
+        let inner = link.style("link");
+        assert_eq!(
+            with_style_stack(|| {
+                Style::new()
+                    .red()
+                    .style(format_args!("[{inner}]"))
+                    .to_string()
+            }),
+            format!("\x1b[31m[{START}link{END}\x1b[31m]\x1b[0m")
+        );
+
+        let inner = Style::new().bold().style("bold");
+        assert_eq!(
+            with_style_stack(|| link.style(format_args!("[{inner}]")).to_string()),
+            format!("{START}[\x1b[1mbold\x1b[0m{START}]{END}")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "supports-colors")]
+    fn test_hyperlink_fallback() {
+        use crate::{LinkFallback, Stream, with_override};
+
+        let link = "docs".hyperlink("https://docs.rs");
+        let text = link.if_supported(Stream::Stdout, LinkFallback::Text);
+        with_override(false, || assert_eq!(text.to_string(), "docs"));
+        with_override(true, || {
+            assert_eq!(
+                text.to_string(),
+                "\x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\"
+            );
+        });
+
+        let link = "docs".hyperlink("https://docs.rs");
+        let dropped =
+            "docs".if_supports_color(Stream::Stdout, |text| text.hyperlink("https://docs.rs"));
+        with_override(false, || {
+            assert_eq!(dropped.to_string(), "docs");
+            assert_eq!(
+                link.if_supported(Stream::Stderr, LinkFallback::TextAndUrl)
+                    .to_string(),
+                "docs (https://docs.rs)"
+            );
+        });
+    }
+}
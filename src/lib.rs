@@ -75,12 +75,91 @@
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
 
+#[cfg(any(feature = "std", feature = "supports-colors"))]
+extern crate std;
+
+/// Returns early from a color formatting function, writing `$color` with `$method` instead, if the
+/// stream currently being displayed (see [`OwoColorize::if_supports_color`]) requires `$color` to
+/// be downgraded.
+macro_rules! if_downgraded {
+    ($color:expr, $method:ident($f:expr)) => {
+        #[cfg(feature = "supports-colors")]
+        if let Some(color) = crate::supports_colors::downgrade($color) {
+            return <crate::DynColors as crate::DynColor>::$method(&color, $f);
+        }
+    };
+}
+
+/// Defines a function which is `const` if the compiler supports floating point arithmetic in const
+/// fns (Rust 1.82+).
+macro_rules! float_const_fn {
+    ($(#[$meta:meta])* $vis:vis fn $name:ident $($rest:tt)*) => {
+        $(#[$meta])*
+        #[cfg(const_float_arithmetic)]
+        $vis const fn $name $($rest)*
+
+        $(#[$meta])*
+        #[cfg(not(const_float_arithmetic))]
+        $vis fn $name $($rest)*
+    };
+}
+
+/// Creates a [`CustomColor`](colors::CustomColor) type from a `#rrggbb` or `#rgb` hex string
+/// literal, for use with [`OwoColorize::fg`] and [`OwoColorize::bg`].
+///
+/// The color is parsed at compile time, so an invalid literal is a compile error. For a value
+/// rather than a type, see [`Rgb::from_hex`].
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, hex};
+///
+/// type Brand = hex!("#2E3192");
+///
+/// println!("{}", "brand blue".fg::<Brand>());
+/// println!("{}", "on yellow".bg::<hex!("#DAAC06")>());
+/// assert_eq!(
+///     "owo".fg::<hex!("#2E3192")>().to_string(),
+///     "owo".fg_rgb::<0x2E, 0x31, 0x92>().to_string()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use owo_colors::{OwoColorize, hex};
+///
+/// println!("{}", "invalid".fg::<hex!("#2E31G2")>());
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:literal) => {
+        $crate::colors::CustomColor<
+            { $crate::Rgb::from_hex($hex).0 },
+            { $crate::Rgb::from_hex($hex).1 },
+            { $crate::Rgb::from_hex($hex).2 },
+        >
+    };
+}
+
+mod ansi_spans;
 pub mod colors;
 mod combo;
 mod dyn_colors;
 mod dyn_styles;
+mod escapes;
+mod gradient;
+mod hyperlink;
+#[cfg(feature = "alloc")]
+pub mod ls_colors;
+mod resets;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod sgr;
+mod strip;
+mod style_parse;
+#[cfg(feature = "std")]
+mod style_stack;
 mod styled_list;
 pub mod styles;
+mod width;
 
 #[cfg(feature = "supports-colors")]
 mod overrides;
@@ -169,10 +248,15 @@ pub struct FgDynColorDisplay<'a, Color: DynColor, T: ?Sized>(&'a T, Color);
 /// coloring is not an option.
 pub struct BgDynColorDisplay<'a, Color: DynColor, T: ?Sized>(&'a T, Color);
 
+/// Wrapper around a type which implements all the formatters the wrapped type does,
+/// with the addition of changing the underline color. Recommended to be constructed using
+/// [`OwoColorize`].
+pub struct UnderlineColorDisplay<'a, Color: DynColor, T: ?Sized>(&'a T, Color);
+
 macro_rules! style_methods {
-    ($(#[$meta:meta] $name:ident $ty:ident),* $(,)?) => {
+    ($($(#[$meta:meta])+ $name:ident $ty:ident),* $(,)?) => {
         $(
-            #[$meta]
+            $(#[$meta])+
             #[must_use]
             #[inline(always)]
             fn $name(&self) -> styles::$ty<'_, Self> {
@@ -250,6 +334,14 @@ const _: () = (); // workaround for syntax highlighting bug
 /// * [`reversed`](OwoColorize::reversed)
 /// * [`hidden`](OwoColorize::hidden)
 /// * [`strikethrough`](OwoColorize::strikethrough)
+/// * [`double_underline`](OwoColorize::double_underline),
+///   [`curly_underline`](OwoColorize::curly_underline),
+///   [`dotted_underline`](OwoColorize::dotted_underline) and
+///   [`dashed_underline`](OwoColorize::dashed_underline)
+/// * [`overline`](OwoColorize::overline)
+/// * [`framed`](OwoColorize::framed) and [`encircled`](OwoColorize::encircled)
+/// * [`superscript`](OwoColorize::superscript) and [`subscript`](OwoColorize::subscript)
+/// * [`fraktur`](OwoColorize::fraktur) and [`font`](OwoColorize::font)
 ///
 /// **Do you want it to only display colors if it's a terminal?**
 ///
@@ -377,6 +469,44 @@ pub trait OwoColorize: Sized {
         hidden HiddenDisplay,
         /// Cross out the text
         strikethrough StrikeThroughDisplay,
+        /// Make the text double underlined. Not all terminals support this; those which don't
+        /// may draw a plain underline or nothing.
+        double_underline DoubleUnderlineDisplay,
+        /// Make the text underlined with a curly line. Not all terminals support this; those
+        /// which don't may draw a plain underline or nothing.
+        curly_underline CurlyUnderlineDisplay,
+        /// Make the text underlined with a dotted line. Not all terminals support this; those
+        /// which don't may draw a plain underline or nothing.
+        dotted_underline DottedUnderlineDisplay,
+        /// Make the text underlined with a dashed line. Not all terminals support this; those
+        /// which don't may draw a plain underline or nothing.
+        dashed_underline DashedUnderlineDisplay,
+        /// Draw a line above the text
+        overline OverlineDisplay,
+        /// Draw a frame around the text. Few terminals support this.
+        framed FramedDisplay,
+        /// Draw a circle around the text. Few terminals support this.
+        encircled EncircledDisplay,
+        /// Make the text superscript. Few terminals support this.
+        superscript SuperscriptDisplay,
+        /// Make the text subscript. Few terminals support this.
+        subscript SubscriptDisplay,
+        /// Display the text in a Fraktur (blackletter) font. Few terminals support this.
+        fraktur FrakturDisplay,
+    }
+
+    /// Display the text in an alternate font, or in the primary font with [`Font::Primary`].
+    /// Which fonts are available depends on the terminal, and most terminals ignore this.
+    ///
+    /// ```rust
+    /// use owo_colors::{Font, OwoColorize};
+    ///
+    /// println!("{}", "alternate font".font(Font::Alt1));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    fn font(&self, font: Font) -> styles::FontDisplay<'_, Self> {
+        styles::FontDisplay::new(self, font)
     }
 
     /// Set the foreground color at runtime. Only use if you do not know which color will be used at
@@ -409,6 +539,20 @@ pub trait OwoColorize: Sized {
         BgDynColorDisplay(self, color)
     }
 
+    /// Set the background color at runtime, along with a black or white foreground color which is
+    /// readable on top of it. See [`Style::on_color_auto_fg`].
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, Rgb};
+    ///
+    /// let user_color = Rgb(46, 49, 146);
+    /// println!("{}", " white on dark blue ".on_color_auto_fg(user_color));
+    /// ```
+    #[must_use]
+    fn on_color_auto_fg<Color: DynColor>(&self, color: Color) -> Styled<&Self> {
+        Style::new().on_color_auto_fg(color).style(self)
+    }
+
     /// Set the foreground color to a specific RGB value.
     #[must_use]
     fn fg_rgb<const R: u8, const G: u8, const B: u8>(
@@ -439,6 +583,103 @@ pub trait OwoColorize: Sized {
         BgDynColorDisplay(self, Rgb(r, g, b))
     }
 
+    /// Set the color of the underline at runtime. This only changes the color of the line, so it
+    /// should be combined with [`underline`](OwoColorize::underline) or another kind of
+    /// underline. Terminals which don't support underline colors draw the underline in the
+    /// foreground color.
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, AnsiColors};
+    ///
+    /// println!("{}", "misspeled".curly_underline().underline_color(AnsiColors::Red));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    fn underline_color<Color: DynColor>(
+        &self,
+        color: Color,
+    ) -> UnderlineColorDisplay<'_, Color, Self> {
+        UnderlineColorDisplay(self, color)
+    }
+
+    /// Sets the color of the underline to an RGB value. See
+    /// [`underline_color`](OwoColorize::underline_color).
+    #[must_use]
+    #[inline(always)]
+    fn underline_truecolor(&self, r: u8, g: u8, b: u8) -> UnderlineColorDisplay<'_, Rgb, Self> {
+        UnderlineColorDisplay(self, Rgb(r, g, b))
+    }
+
+    /// Turn the text into a clickable link to `url`, in terminals which support
+    /// [OSC 8 hyperlinks](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda).
+    /// Terminals which don't support them display the text on its own.
+    ///
+    /// See [`HyperlinkDisplay`] for setting the link's id and for combining links with styles.
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// println!("See the {} for details", "documentation".hyperlink("https://docs.rs/owo-colors"));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    fn hyperlink<'a>(&'a self, url: &'a str) -> HyperlinkDisplay<'a, Self> {
+        HyperlinkDisplay::new(self, url)
+    }
+
+    /// Color the foreground of each character along a gradient from `start` to `end`.
+    ///
+    /// Colors are interpolated in a perceptual color space. See [`GradientDisplay`] for details.
+    ///
+    /// Without the `alloc` feature, the value is formatted twice, so it must display the same
+    /// text each time.
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, Rgb};
+    ///
+    /// println!("{}", "sunset".gradient(Rgb(255, 94, 58), Rgb(106, 48, 147)));
+    /// ```
+    #[must_use]
+    fn gradient(&self, start: impl Into<Rgb>, end: impl Into<Rgb>) -> GradientDisplay<'_, Self> {
+        GradientDisplay::new(self, start.into(), end.into(), false)
+    }
+
+    /// Color the background of each character along a gradient from `start` to `end`.
+    ///
+    /// See [`OwoColorize::gradient`].
+    #[must_use]
+    fn on_gradient(&self, start: impl Into<Rgb>, end: impl Into<Rgb>) -> GradientDisplay<'_, Self> {
+        GradientDisplay::new(self, start.into(), end.into(), true)
+    }
+
+    /// Color the foreground of each character along a gradient through evenly spaced stops.
+    ///
+    /// With no stops the value is displayed uncolored, and with a single stop it is displayed in
+    /// that color. See [`GradientDisplay`] for details.
+    ///
+    /// Without the `alloc` feature, the value is formatted twice, so it must display the same
+    /// text each time.
+    ///
+    /// ```rust
+    /// use owo_colors::{CssColors, OwoColorize, Rgb};
+    ///
+    /// let rainbow = [CssColors::Red, CssColors::Orange, CssColors::Yellow, CssColors::Green]
+    ///     .map(Rgb::from);
+    /// println!("{}", "[==========>    ]".gradient_stops(&rainbow));
+    /// ```
+    #[must_use]
+    fn gradient_stops<'a>(&'a self, stops: &'a [Rgb]) -> GradientDisplay<'a, Self> {
+        GradientDisplay::with_stops(self, stops, false)
+    }
+
+    /// Color the background of each character along a gradient through evenly spaced stops.
+    ///
+    /// See [`OwoColorize::gradient_stops`].
+    #[must_use]
+    fn on_gradient_stops<'a>(&'a self, stops: &'a [Rgb]) -> GradientDisplay<'a, Self> {
+        GradientDisplay::with_stops(self, stops, true)
+    }
+
     /// Apply a runtime-determined style
     #[must_use]
     fn style(&self, style: Style) -> Styled<&Self> {
@@ -449,6 +690,9 @@ pub trait OwoColorize: Sized {
     /// supports at least basic ANSI colors, allowing you to conditionally apply
     /// given styles/colors.
     ///
+    /// If the stream doesn't support 24-bit colors, any RGB, CSS or Xterm colors applied by the
+    /// transformation are replaced with the nearest color the stream can display.
+    ///
     /// Requires the `supports-colors` feature.
     ///
     /// ```rust
@@ -493,21 +737,31 @@ mod supports_colors;
 
 #[cfg(feature = "supports-colors")]
 pub use {
+    hyperlink::LinkFallback,
     overrides::{set_override, unset_override, with_override},
-    supports_colors::{Stream, SupportsColorsDisplay},
+    supports_colors::{Stream, SupportsColorsDisplay, with_palette},
 };
 
 pub use colors::{
-    ansi_colors::AnsiColors, css::dynamic::CssColors, dynamic::Rgb, xterm::dynamic::XtermColors,
+    ansi_colors::AnsiColors, css::dynamic::CssColors, dynamic::Rgb, palette::Palette,
+    quantize::ColorDepth, xterm::dynamic::XtermColors,
 };
 
 // TODO: figure out some wait to only implement for fmt::Display | fmt::Debug | ...
 impl<D> OwoColorize for D {}
 
 pub use {
+    ansi_spans::{AnsiSpans, ansi_spans},
     combo::{ComboColorDisplay, ComboDynColorDisplay},
     dyn_colors::*,
     dyn_styles::*,
+    gradient::GradientDisplay,
+    hyperlink::HyperlinkDisplay,
+    resets::{ResetMode, reset_mode, set_reset_mode},
+    sgr::{ParseSgrError, ParseSgrErrorKind},
+    strip::Stripped,
+    style_parse::{ParseStyleError, ParseStyleErrorKind},
+    width::visible_width,
 };
 
 /// Module for drop-in [`colored`](https://docs.rs/colored) support to aid in porting code from
@@ -536,9 +790,11 @@ pub mod colored {
     }
 }
 
-pub use styled_list::StyledList;
+pub use styled_list::{StyleTransition, StyledList};
 #[cfg(feature = "alloc")]
-pub use styled_list::StyledVec;
+pub use {ansi_spans::parse_ansi, strip::strip, styled_list::StyledVec};
+#[cfg(feature = "std")]
+pub use {resets::with_reset_mode, style_stack::with_style_stack};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,833 @@
+//! Parsing `LS_COLORS` and `dircolors` databases, to color file names the same way as `ls`.
+//!
+//! ```rust
+//! use owo_colors::{Style, ls_colors::{Indicator, LsColors}};
+//!
+//! let ls_colors: LsColors = "di=01;34:ln=01;36:*.tar=01;31:*.rs=38;5;208".parse().unwrap();
+//! assert_eq!(
+//!     ls_colors.style_for_indicator(Indicator::Directory),
+//!     Some(Style::new().bold().blue())
+//! );
+//! assert_eq!(
+//!     ls_colors.style_for_name("main.rs"),
+//!     Some(Style::new().color(owo_colors::XtermColors::FlushOrange))
+//! );
+//! assert_eq!(ls_colors.style_for_name("Cargo.toml"), None);
+//! ```
+extern crate alloc;
+
+use crate::sgr::apply_sgr_lossy;
+use crate::{ParseSgrErrorKind, Style};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::{fs::Metadata, path::Path};
+
+macro_rules! indicators {
+    ($($(#[$meta:meta])* $name:ident = $code:literal),* $(,)?) => {
+        /// A kind of file which can be given its own style, named by a two-letter code in
+        /// `LS_COLORS`, such as `di` for directories.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum Indicator {
+            $(
+                $(#[$meta])*
+                $name,
+            )*
+        }
+
+        impl Indicator {
+            /// All the indicators, in the order they're declared in
+            pub const ALL: &'static [Indicator] = &[$(Indicator::$name,)*];
+
+            /// Returns the two-letter code of the indicator in `LS_COLORS`, such as `"di"`.
+            #[must_use]
+            pub const fn code(self) -> &'static str {
+                match self {
+                    $(Indicator::$name => $code,)*
+                }
+            }
+
+            /// Returns the indicator with the given two-letter code, such as `"di"`.
+            #[must_use]
+            pub fn from_code(code: &str) -> Option<Self> {
+                match code {
+                    $($code => Some(Indicator::$name),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+indicators! {
+    /// Text which isn't a file name (`no`)
+    Normal = "no",
+    /// A regular file (`fi`)
+    File = "fi",
+    /// A directory (`di`)
+    Directory = "di",
+    /// A symbolic link (`ln`)
+    Symlink = "ln",
+    /// A symbolic link to a file which doesn't exist (`or`)
+    OrphanedSymlink = "or",
+    /// The file which an orphaned symbolic link points to, as shown by `ls -l` (`mi`). See
+    /// [`LsColors::style_for_link_target`].
+    MissingTarget = "mi",
+    /// A named pipe (`pi`)
+    Fifo = "pi",
+    /// A socket (`so`)
+    Socket = "so",
+    /// A block device (`bd`)
+    BlockDevice = "bd",
+    /// A character device (`cd`)
+    CharDevice = "cd",
+    /// A door, on Solaris (`do`)
+    Door = "do",
+    /// An executable file (`ex`)
+    Executable = "ex",
+    /// A file with the setuid bit set (`su`)
+    Setuid = "su",
+    /// A file with the setgid bit set (`sg`)
+    Setgid = "sg",
+    /// A directory with the sticky bit set, which isn't writable by others (`st`)
+    Sticky = "st",
+    /// A directory writable by others, without the sticky bit (`ow`)
+    OtherWritable = "ow",
+    /// A directory writable by others, with the sticky bit set (`tw`)
+    StickyOtherWritable = "tw",
+    /// A file with capabilities (`ca`)
+    Capability = "ca",
+    /// A regular file with more than one hard link (`mh`)
+    MultiHardlink = "mh",
+}
+
+/// Codes for the escape sequences `ls` writes around file names, rather than styles, which are
+/// accepted and ignored.
+const TERMINAL_CODES: [&str; 5] = ["lc", "rc", "ec", "rs", "cl"];
+
+/// The keywords of a `dircolors` database, and the `LS_COLORS` codes they stand for.
+const DIRCOLORS_KEYWORDS: [(&str, &str); 37] = [
+    ("NORMAL", "no"),
+    ("NORM", "no"),
+    ("FILE", "fi"),
+    ("RESET", "rs"),
+    ("DIR", "di"),
+    ("LNK", "ln"),
+    ("LINK", "ln"),
+    ("SYMLINK", "ln"),
+    ("ORPHAN", "or"),
+    ("MISSING", "mi"),
+    ("FIFO", "pi"),
+    ("PIPE", "pi"),
+    ("SOCK", "so"),
+    ("BLK", "bd"),
+    ("BLOCK", "bd"),
+    ("CHR", "cd"),
+    ("CHAR", "cd"),
+    ("DOOR", "do"),
+    ("EXEC", "ex"),
+    ("LEFT", "lc"),
+    ("LEFTCODE", "lc"),
+    ("RIGHT", "rc"),
+    ("RIGHTCODE", "rc"),
+    ("END", "ec"),
+    ("ENDCODE", "ec"),
+    ("SUID", "su"),
+    ("SETUID", "su"),
+    ("SGID", "sg"),
+    ("SETGID", "sg"),
+    ("STICKY", "st"),
+    ("OTHER_WRITABLE", "ow"),
+    ("OWR", "ow"),
+    ("STICKY_OTHER_WRITABLE", "tw"),
+    ("OWT", "tw"),
+    ("CAPABILITY", "ca"),
+    ("MULTIHARDLINK", "mh"),
+    ("CLRTOEOL", "cl"),
+];
+
+/// The styles used by GNU `ls` for the indicators which aren't set in `LS_COLORS`.
+const GNU_DEFAULTS: [(Indicator, Style); 13] = [
+    (Indicator::Directory, Style::new().bold().blue()),
+    (Indicator::Symlink, Style::new().bold().cyan()),
+    (Indicator::Fifo, Style::new().yellow()),
+    (Indicator::Socket, Style::new().bold().magenta()),
+    (Indicator::BlockDevice, Style::new().bold().yellow()),
+    (Indicator::CharDevice, Style::new().bold().yellow()),
+    (Indicator::Executable, Style::new().bold().green()),
+    (Indicator::Door, Style::new().bold().magenta()),
+    (Indicator::Setuid, Style::new().white().on_red()),
+    (Indicator::Setgid, Style::new().black().on_yellow()),
+    (Indicator::Sticky, Style::new().white().on_blue()),
+    (Indicator::OtherWritable, Style::new().blue().on_green()),
+    (
+        Indicator::StickyOtherWritable,
+        Style::new().black().on_green(),
+    ),
+];
+
+/// An error for when an `LS_COLORS` value or a `dircolors` database can not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLsColorsError {
+    kind: ParseLsColorsErrorKind,
+    position: usize,
+}
+
+/// The reason `LS_COLORS` could not be parsed, see [`ParseLsColorsError::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLsColorsErrorKind {
+    /// A key is neither a file type code (or a `dircolors` keyword) nor a pattern starting with
+    /// `*` (or `.`)
+    UnknownKey,
+    /// A key doesn't have a value
+    MissingValue,
+    /// A value contains something other than numbers separated by `;` or `:`. Numbers which
+    /// aren't supported SGR codes are skipped instead.
+    InvalidCodes(ParseSgrErrorKind),
+}
+
+impl ParseLsColorsError {
+    const fn new(kind: ParseLsColorsErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// The reason `LS_COLORS` could not be parsed
+    #[must_use]
+    pub const fn kind(&self) -> ParseLsColorsErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the parsed string at which the error was found
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseLsColorsErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseLsColorsErrorKind::UnknownKey => "unknown file type",
+            ParseLsColorsErrorKind::MissingValue => "missing value",
+            ParseLsColorsErrorKind::InvalidCodes(kind) => return write!(f, "{}", kind),
+        })
+    }
+}
+
+impl fmt::Display for ParseLsColorsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+/// The styles `ls` gives to file names, by file type and by pattern, parsed from `LS_COLORS` or
+/// a `dircolors` database.
+///
+/// As in GNU `ls`, file types which aren't given a style use the default styles of `ls`, such as
+/// bold blue for directories. Setting a style to `0` removes it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LsColors {
+    indicators: [Option<Style>; Indicator::ALL.len()],
+    patterns: Vec<(String, Style)>,
+    link_target: bool,
+}
+
+impl Default for LsColors {
+    /// The default styles of GNU `ls`, used when `LS_COLORS` isn't set
+    fn default() -> Self {
+        let mut indicators = [None; Indicator::ALL.len()];
+        for (indicator, style) in GNU_DEFAULTS {
+            indicators[indicator as usize] = Some(style);
+        }
+        Self {
+            indicators,
+            patterns: Vec::new(),
+            link_target: false,
+        }
+    }
+}
+
+impl LsColors {
+    /// Reads the styles from the `LS_COLORS` environment variable, or returns the default styles
+    /// if it isn't set or isn't valid Unicode.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<Self, ParseLsColorsError> {
+        match std::env::var("LS_COLORS") {
+            Ok(ls_colors) => ls_colors.parse(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Parses a `dircolors` database, in the format printed by `dircolors --print-database`.
+    ///
+    /// Each line holds a keyword such as `DIR`, or a pattern such as `.tar` or `*README`,
+    /// followed by SGR codes. `TERM` and `COLORTERM` lines are ignored, so all the entries apply
+    /// whatever the terminal.
+    ///
+    /// ```rust
+    /// use owo_colors::{Style, ls_colors::{Indicator, LsColors}};
+    ///
+    /// let ls_colors = LsColors::from_dircolors(
+    ///     "# Directories\nTERM xterm*\nDIR 01;34 # bold blue\n.tar 01;31\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     ls_colors.style_for_indicator(Indicator::Directory),
+    ///     Some(Style::new().bold().blue())
+    /// );
+    /// assert_eq!(ls_colors.style_for_name("a.tar"), Some(Style::new().bold().red()));
+    /// ```
+    pub fn from_dircolors(database: &str) -> Result<Self, ParseLsColorsError> {
+        let mut ls_colors = Self::default();
+        let mut position = 0;
+        for line in database.split_inclusive('\n') {
+            let start = position;
+            position += line.len();
+
+            // Comments start with `#` at the start of the line or after whitespace
+            let mut end = line.len();
+            let mut previous = ' ';
+            for (i, c) in line.char_indices() {
+                if c == '#' && previous.is_whitespace() {
+                    end = i;
+                    break;
+                }
+                previous = c;
+            }
+            let line = &line[..end];
+
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let key_start = start + (line.len() - trimmed.len());
+            let (key, value) = trimmed
+                .split_once(char::is_whitespace)
+                .unwrap_or((trimmed, ""));
+            let value_start = key_start + (trimmed.len() - value.trim_start().len());
+            let value = value.trim();
+            if ["TERM", "COLORTERM", "COLOR", "OPTIONS", "EIGHTBIT"]
+                .iter()
+                .any(|ignored| key.eq_ignore_ascii_case(ignored))
+            {
+                continue;
+            }
+            if value.is_empty() {
+                return Err(ParseLsColorsError::new(
+                    ParseLsColorsErrorKind::MissingValue,
+                    value_start,
+                ));
+            }
+
+            if key.starts_with('.') {
+                let mut pattern = String::from("*");
+                pattern.push_str(key);
+                ls_colors.set_pattern(pattern, value, value_start)?;
+            } else if key.starts_with('*') {
+                ls_colors.set_pattern(key.into(), value, value_start)?;
+            } else {
+                let code = DIRCOLORS_KEYWORDS
+                    .iter()
+                    .find(|(keyword, _)| key.eq_ignore_ascii_case(keyword))
+                    .map(|(_, code)| *code)
+                    .ok_or(ParseLsColorsError::new(
+                        ParseLsColorsErrorKind::UnknownKey,
+                        key_start,
+                    ))?;
+                ls_colors.set_code(code, value, value_start)?;
+            }
+        }
+        Ok(ls_colors)
+    }
+
+    fn set_code(
+        &mut self,
+        code: &str,
+        value: &str,
+        position: usize,
+    ) -> Result<(), ParseLsColorsError> {
+        if TERMINAL_CODES.contains(&code) {
+            return Ok(());
+        }
+        let indicator = Indicator::from_code(code).ok_or(ParseLsColorsError::new(
+            ParseLsColorsErrorKind::UnknownKey,
+            position,
+        ))?;
+        if indicator == Indicator::Symlink && value == "target" {
+            self.link_target = true;
+            return Ok(());
+        }
+        self.indicators[indicator as usize] = parse_style(value, position)?;
+        Ok(())
+    }
+
+    fn set_pattern(
+        &mut self,
+        pattern: String,
+        value: &str,
+        position: usize,
+    ) -> Result<(), ParseLsColorsError> {
+        let style = parse_style(value, position)?.unwrap_or_default();
+        self.patterns.push((pattern, style));
+        Ok(())
+    }
+
+    /// Returns the style of a kind of file, if it has one.
+    #[must_use]
+    pub const fn style_for_indicator(&self, indicator: Indicator) -> Option<Style> {
+        self.indicators[indicator as usize]
+    }
+
+    /// Returns true if symbolic links are given the style of the file they point to, which is
+    /// set with `ln=target`. Links to files which don't exist are then always given the style of
+    /// [`Indicator::OrphanedSymlink`], or of [`Indicator::Normal`] if that isn't set.
+    #[must_use]
+    pub const fn link_target(&self) -> bool {
+        self.link_target
+    }
+
+    /// Returns the style of the pattern which matches a file name, such as `*.tar` for
+    /// `archive.tar`, if any.
+    ///
+    /// When several patterns match, the last one wins. Patterns are matched case-sensitively
+    /// first, then ignoring ASCII case, as in GNU `ls`.
+    #[must_use]
+    pub fn style_for_name(&self, name: &str) -> Option<Style> {
+        [false, true].into_iter().find_map(|ignore_case| {
+            self.patterns
+                .iter()
+                .rev()
+                .find(|(pattern, _)| glob_matches(pattern, name, ignore_case))
+                .map(|(_, style)| *style)
+        })
+    }
+
+    /// Returns the style `ls` would give to the file at `path`, given its metadata from
+    /// [`std::fs::symlink_metadata`] (or [`DirEntry::metadata`](std::fs::DirEntry::metadata),
+    /// which doesn't follow symbolic links either).
+    ///
+    /// Directories, symbolic links and other special files are styled by their type. Regular
+    /// files are styled by their permissions if they're executable, setuid or setgid, or by the
+    /// patterns matching their name otherwise. Capabilities aren't detected.
+    ///
+    /// ```rust,no_run
+    /// use owo_colors::{OwoColorize, ls_colors::LsColors};
+    ///
+    /// let ls_colors = LsColors::from_env().unwrap_or_default();
+    /// for entry in std::fs::read_dir(".")? {
+    ///     let entry = entry?;
+    ///     let style = ls_colors.style_for(&entry.metadata()?, entry.path());
+    ///     println!("{}", entry.file_name().to_string_lossy().style(style));
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn style_for(&self, metadata: &Metadata, path: impl AsRef<Path>) -> Style {
+        let path = path.as_ref();
+        let file_type = metadata.file_type();
+        let colored = |indicator: Indicator| self.style_for_indicator(indicator).is_some();
+
+        #[cfg(unix)]
+        let mode = std::os::unix::fs::PermissionsExt::mode(&metadata.permissions());
+        #[cfg(not(unix))]
+        let mode = 0;
+
+        let indicator = if file_type.is_dir() {
+            let sticky = mode & 0o1000 != 0;
+            let other_writable = mode & 0o002 != 0;
+            if sticky && other_writable && colored(Indicator::StickyOtherWritable) {
+                Indicator::StickyOtherWritable
+            } else if other_writable && colored(Indicator::OtherWritable) {
+                Indicator::OtherWritable
+            } else if sticky && colored(Indicator::Sticky) {
+                Indicator::Sticky
+            } else {
+                Indicator::Directory
+            }
+        } else if file_type.is_symlink() {
+            // Like `ls`, a link without a target can't be styled as its target, so it's an
+            // orphan even if orphans aren't colored
+            match path.metadata() {
+                Ok(target) if self.link_target => return self.style_for(&target, path),
+                Err(_) if self.link_target || colored(Indicator::OrphanedSymlink) => {
+                    Indicator::OrphanedSymlink
+                }
+                _ => Indicator::Symlink,
+            }
+        } else if file_type.is_file() {
+            #[cfg(unix)]
+            let links = std::os::unix::fs::MetadataExt::nlink(metadata);
+            #[cfg(not(unix))]
+            let links = 1;
+
+            if mode & 0o4000 != 0 && colored(Indicator::Setuid) {
+                Indicator::Setuid
+            } else if mode & 0o2000 != 0 && colored(Indicator::Setgid) {
+                Indicator::Setgid
+            } else if mode & 0o111 != 0 && colored(Indicator::Executable) {
+                Indicator::Executable
+            } else if links > 1 && colored(Indicator::MultiHardlink) {
+                Indicator::MultiHardlink
+            } else {
+                Indicator::File
+            }
+        } else {
+            special_file_indicator(&file_type)
+        };
+
+        let pattern_style = match (indicator, path.file_name()) {
+            (Indicator::File, Some(name)) => self.style_for_name(&name.to_string_lossy()),
+            _ => None,
+        };
+        pattern_style
+            .or_else(|| self.style_for_indicator(indicator))
+            .or_else(|| self.style_for_indicator(Indicator::Normal))
+            .unwrap_or_default()
+    }
+
+    /// Returns the style `ls -l` would give to the target of the symbolic link at `link`, which
+    /// is shown after the link's name as in `link -> target`.
+    ///
+    /// If the target exists, it's styled like any other file by [`style_for`](Self::style_for),
+    /// using the name of the target for patterns. If it doesn't, it's given the style of
+    /// [`Indicator::MissingTarget`] (`mi`), or of [`Indicator::OrphanedSymlink`] (`or`) if that
+    /// isn't set.
+    ///
+    /// ```rust,no_run
+    /// use owo_colors::{OwoColorize, ls_colors::LsColors};
+    ///
+    /// let ls_colors = LsColors::from_env().unwrap_or_default();
+    /// let link = std::path::Path::new("link");
+    /// let target = std::fs::read_link(link)?;
+    /// println!(
+    ///     "{} -> {}",
+    ///     "link".style(ls_colors.style_for(&link.symlink_metadata()?, link)),
+    ///     target.display().style(ls_colors.style_for_link_target(link)),
+    /// );
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn style_for_link_target(&self, link: impl AsRef<Path>) -> Style {
+        let link = link.as_ref();
+        match link.metadata() {
+            Ok(metadata) => {
+                let target = std::fs::read_link(link);
+                self.style_for(&metadata, target.as_deref().unwrap_or(link))
+            }
+            Err(_) => self
+                .style_for_indicator(Indicator::MissingTarget)
+                .or_else(|| self.style_for_indicator(Indicator::OrphanedSymlink))
+                .or_else(|| self.style_for_indicator(Indicator::Normal))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+fn special_file_indicator(file_type: &std::fs::FileType) -> Indicator {
+    use std::os::unix::fs::FileTypeExt;
+
+    if file_type.is_fifo() {
+        Indicator::Fifo
+    } else if file_type.is_socket() {
+        Indicator::Socket
+    } else if file_type.is_block_device() {
+        Indicator::BlockDevice
+    } else if file_type.is_char_device() {
+        Indicator::CharDevice
+    } else {
+        Indicator::Normal
+    }
+}
+
+#[cfg(all(feature = "std", not(unix)))]
+fn special_file_indicator(_: &std::fs::FileType) -> Indicator {
+    Indicator::Normal
+}
+
+/// Parses the SGR codes of an entry, returning `None` for codes which don't style anything, such
+/// as `0`.
+///
+/// `ls` writes the codes to the terminal as they are, so codes which a `Style` can't represent,
+/// such as `26` or `38;5;300`, are skipped rather than rejecting the whole database.
+fn parse_style(value: &str, position: usize) -> Result<Option<Style>, ParseLsColorsError> {
+    if let Some(invalid) = value.find(|c: char| !c.is_ascii_digit() && c != ';' && c != ':') {
+        return Err(ParseLsColorsError::new(
+            ParseLsColorsErrorKind::InvalidCodes(ParseSgrErrorKind::InvalidNumber),
+            position + invalid,
+        ));
+    }
+    let style = apply_sgr_lossy(Style::new(), value);
+    Ok((!style.is_plain()).then_some(style))
+}
+
+/// Returns true if `name` matches `pattern`, in which `*` matches any text and `?` any character.
+fn glob_matches(pattern: &str, name: &str, ignore_case: bool) -> bool {
+    let (mut pattern_rest, mut name_rest) = (pattern, name);
+    // Where to resume after the last `*`, matching one more character with it
+    let mut backtrack = None;
+    loop {
+        let mut pattern_chars = pattern_rest.chars();
+        let mut name_chars = name_rest.chars();
+        match (pattern_chars.next(), name_chars.next()) {
+            (Some('*'), _) => {
+                pattern_rest = pattern_chars.as_str();
+                backtrack = Some((pattern_rest, name_rest));
+                continue;
+            }
+            (Some(p), Some(n))
+                if p == '?' || p == n || (ignore_case && p.eq_ignore_ascii_case(&n)) =>
+            {
+                pattern_rest = pattern_chars.as_str();
+                name_rest = name_chars.as_str();
+                continue;
+            }
+            (None, None) => return true,
+            _ => {}
+        }
+
+        match backtrack {
+            Some((pattern_after_star, name_at_star)) => {
+                let mut name_chars = name_at_star.chars();
+                if name_chars.next().is_none() {
+                    return false;
+                }
+                pattern_rest = pattern_after_star;
+                name_rest = name_chars.as_str();
+                backtrack = Some((pattern_rest, name_rest));
+            }
+            None => return false,
+        }
+    }
+}
+
+/// Parses an `LS_COLORS` value, such as `di=01;34:ln=01;36:*.tar=01;31`.
+///
+/// Entries are separated by `:`, and map a two-letter file type code (see [`Indicator`]) or a
+/// pattern starting with `*` to SGR codes. `ln=target` gives symbolic links the style of the file
+/// they point to.
+impl core::str::FromStr for LsColors {
+    type Err = ParseLsColorsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ls_colors = Self::default();
+        let mut position = 0;
+        for entry in s.split(':') {
+            let start = position;
+            position += entry.len() + 1;
+            if entry.is_empty() {
+                continue;
+            }
+
+            let (key, value) = entry.split_once('=').ok_or(ParseLsColorsError::new(
+                ParseLsColorsErrorKind::MissingValue,
+                start + entry.len(),
+            ))?;
+            let value_start = start + key.len() + 1;
+            if key.starts_with('*') {
+                ls_colors.set_pattern(key.into(), value, value_start)?;
+            } else if key.len() == 2 {
+                ls_colors
+                    .set_code(key, value, value_start)
+                    .map_err(|error| match error.kind {
+                        ParseLsColorsErrorKind::UnknownKey => {
+                            ParseLsColorsError::new(ParseLsColorsErrorKind::UnknownKey, start)
+                        }
+                        _ => error,
+                    })?;
+            } else {
+                return Err(ParseLsColorsError::new(
+                    ParseLsColorsErrorKind::UnknownKey,
+                    start,
+                ));
+            }
+        }
+        Ok(ls_colors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnsiColors, DynColors};
+
+    #[test]
+    fn test_parse_ls_colors() {
+        let ls_colors: LsColors = "rs=0:di=01;34:ln=target:or=40;31;01:ex=:fi=00:*.tar=01;31:\
+            *.TAR=01;32:*.rs=38;2;255;136;0:*~=90:*README=4"
+            .parse()
+            .unwrap();
+        assert!(ls_colors.link_target());
+        assert_eq!(
+            ls_colors.style_for_indicator(Indicator::OrphanedSymlink),
+            Some(Style::new().on_black().red().bold())
+        );
+        assert_eq!(ls_colors.style_for_indicator(Indicator::Executable), None);
+        assert_eq!(ls_colors.style_for_indicator(Indicator::File), None);
+        assert_eq!(
+            ls_colors.style_for_indicator(Indicator::Setuid),
+            Some(Style::new().white().on_red())
+        );
+
+        assert_eq!(
+            ls_colors.style_for_name("a.tar"),
+            Some(Style::new().bold().red())
+        );
+        assert_eq!(
+            ls_colors.style_for_name("a.TAR"),
+            Some(Style::new().bold().green())
+        );
+        assert_eq!(
+            ls_colors.style_for_name("a.Tar"),
+            Some(Style::new().bold().green())
+        );
+        assert_eq!(
+            ls_colors
+                .style_for_name("main.rs")
+                .and_then(|style| style.get_fg()),
+            Some(DynColors::Rgb(255, 136, 0))
+        );
+        assert_eq!(
+            ls_colors.style_for_name("notes~"),
+            Some(Style::new().color(AnsiColors::BrightBlack))
+        );
+        assert_eq!(
+            ls_colors.style_for_name("README"),
+            Some(Style::new().underline())
+        );
+        assert_eq!(ls_colors.style_for_name("README.md"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |s: &str| {
+            let error = s.parse::<LsColors>().unwrap_err();
+            (error.kind(), error.position())
+        };
+        assert_eq!(
+            error("di=01:xx=01"),
+            (ParseLsColorsErrorKind::UnknownKey, 6)
+        );
+        assert_eq!(error("di=01:ex"), (ParseLsColorsErrorKind::MissingValue, 8));
+        assert_eq!(
+            error("di=01:*.a=01;x"),
+            (
+                ParseLsColorsErrorKind::InvalidCodes(ParseSgrErrorKind::InvalidNumber),
+                13
+            )
+        );
+
+        assert_eq!(
+            error("di=01:fi=3m"),
+            (
+                ParseLsColorsErrorKind::InvalidCodes(ParseSgrErrorKind::InvalidNumber),
+                10
+            )
+        );
+
+        let error = LsColors::from_dircolors("DIR 01\n  FOO 1\n").unwrap_err();
+        assert_eq!(
+            (error.kind(), error.position()),
+            (ParseLsColorsErrorKind::UnknownKey, 9)
+        );
+        let error = LsColors::from_dircolors("DIR 01\nEXEC # none\n").unwrap_err();
+        assert_eq!(
+            (error.kind(), error.position()),
+            (ParseLsColorsErrorKind::MissingValue, 12)
+        );
+    }
+
+    #[test]
+    fn test_unsupported_codes() {
+        let ls_colors: LsColors = "di=26:ln=60;01:ex=38;5;300:so=01;38;5;300;4:*.x=05;99;31"
+            .parse()
+            .unwrap();
+        assert_eq!(ls_colors.style_for_indicator(Indicator::Directory), None);
+        assert_eq!(
+            ls_colors.style_for_indicator(Indicator::Symlink),
+            Some(Style::new().bold())
+        );
+        assert_eq!(ls_colors.style_for_indicator(Indicator::Executable), None);
+        assert_eq!(
+            ls_colors.style_for_indicator(Indicator::Socket),
+            Some(Style::new().bold().underline())
+        );
+        assert_eq!(
+            ls_colors.style_for_name("a.x"),
+            Some(Style::new().blink().red())
+        );
+
+        let ls_colors = LsColors::from_dircolors("DIR 26\nLINK 01;60\n").unwrap();
+        assert_eq!(ls_colors.style_for_indicator(Indicator::Directory), None);
+        assert_eq!(
+            ls_colors.style_for_indicator(Indicator::Symlink),
+            Some(Style::new().bold())
+        );
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("*.tar.gz", "a.tar.gz", false));
+        assert!(!glob_matches("*.tar.gz", "a.tar", false));
+        assert!(glob_matches("*a*b?", "xxaxxbbz", false));
+        assert!(!glob_matches("*a*b?", "xxaxxb", false));
+        assert!(glob_matches("*.JPG", "photo.jpg", true));
+        assert!(glob_matches("*", "", false));
+        assert!(glob_matches("?é*", "éé", false));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", unix))]
+    fn test_style_for() {
+        use std::{fs, os::unix::fs::PermissionsExt};
+
+        let dir = std::env::temp_dir().join(format!("owo-colors-ls-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir(&dir).unwrap();
+        let file = dir.join("a.tar");
+        let script = dir.join("run.sh");
+        let orphan = dir.join("orphan");
+        fs::write(&file, "").unwrap();
+        fs::write(&script, "").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        std::os::unix::fs::symlink(dir.join("missing"), &orphan).unwrap();
+
+        let style_for = |ls_colors: &LsColors, path: &Path| {
+            ls_colors.style_for(&fs::symlink_metadata(path).unwrap(), path)
+        };
+        let ls_colors: LsColors = "no=02:or=31:*.tar=01;31:*.sh=32".parse().unwrap();
+        assert_eq!(style_for(&ls_colors, &dir), Style::new().bold().blue());
+        assert_eq!(style_for(&ls_colors, &file), Style::new().bold().red());
+        assert_eq!(style_for(&ls_colors, &script), Style::new().bold().green());
+        assert_eq!(style_for(&ls_colors, &orphan), Style::new().red());
+
+        let ls_colors: LsColors = "no=02:ex=00:ln=target".parse().unwrap();
+        assert_eq!(style_for(&ls_colors, &script), Style::new().dimmed());
+        assert_eq!(style_for(&ls_colors, &orphan), Style::new().dimmed());
+        let ls_colors: LsColors = "ln=target".parse().unwrap();
+        assert_eq!(style_for(&ls_colors, &orphan), Style::new());
+        let ls_colors: LsColors = "or=31:ln=target".parse().unwrap();
+        assert_eq!(style_for(&ls_colors, &orphan), Style::new().red());
+
+        // The targets of symbolic links, as shown by `ls -l`
+        let link = dir.join("link");
+        std::os::unix::fs::symlink("a.tar", &link).unwrap();
+        let ls_colors: LsColors = "or=31:mi=05;31:*.tar=01;31".parse().unwrap();
+        assert_eq!(
+            ls_colors.style_for_link_target(&link),
+            Style::new().bold().red()
+        );
+        assert_eq!(
+            ls_colors.style_for_link_target(&orphan),
+            Style::new().blink().red()
+        );
+        let ls_colors: LsColors = "or=31".parse().unwrap();
+        assert_eq!(ls_colors.style_for_link_target(&orphan), Style::new().red());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
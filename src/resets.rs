@@ -0,0 +1,195 @@
+//! Choosing how styles are turned off at the end of the text they apply to.
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// How styles are turned off at the end of the text they apply to. See [`set_reset_mode`] and
+/// `with_reset_mode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResetMode {
+    /// Reset all attributes with `\x1b[0m`, including those set by styles the text is nested in.
+    #[default]
+    Full,
+    /// Only turn off the attributes which were turned on, for example with `\x1b[22m` after bold
+    /// text or `\x1b[39m` after a foreground color, so that the styles of enclosing text are kept.
+    ///
+    /// Terminals turn off bold and dimmed text with the same code (22), so bold text nested in
+    /// dimmed text still ends the dimmed text, and likewise for the other attributes which share
+    /// a code: italic and Fraktur (23), blink and fast blink (25), framed and encircled (54), and
+    /// superscript and subscript (75).
+    Granular,
+}
+
+static GRANULAR: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The mode set by [`with_reset_mode`] on this thread, which takes priority over the mode set
+    /// by [`set_reset_mode`].
+    static SCOPED: core::cell::Cell<Option<ResetMode>> = const { core::cell::Cell::new(None) };
+}
+
+/// Set how styles are turned off at the end of the text they apply to, for all styled values
+/// displayed afterwards. This affects [`Style`](crate::Style)s, [`StyledList`](crate::StyledList)s
+/// and effects such as [`bold`](crate::OwoColorize::bold). Colors applied without a `Style` are
+/// always turned off on their own.
+///
+/// # Global effect
+///
+/// The mode is shared by the whole process: setting it changes the output of every thread and
+/// of every crate using owo-colors, and libraries which set different modes interfere with each
+/// other. It should only be set by applications, once, before anything is displayed. Libraries
+/// should use `with_reset_mode` (with the `std` feature) instead, which only applies to values
+/// displayed on the current thread while a closure runs.
+///
+/// Granular resets let styled values be nested, even if the inner value is styled by another
+/// library:
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, ResetMode, Style, set_reset_mode};
+///
+/// let inner = format!("{}", "inner".italic());
+/// let outer = format!("{} tail", inner).bold().to_string();
+/// assert_eq!(outer, "\x1b[1m\x1b[3minner\x1b[0m tail\x1b[0m");
+///
+/// set_reset_mode(ResetMode::Granular);
+/// let inner = format!("{}", "inner".italic());
+/// let outer = format!("{} tail", inner).bold().to_string();
+/// assert_eq!(outer, "\x1b[1m\x1b[3minner\x1b[23m tail\x1b[22m");
+///
+/// let style = Style::new().red().underline();
+/// assert_eq!(style.style("text").to_string(), "\x1b[31;4mtext\x1b[39;24m");
+/// # set_reset_mode(ResetMode::Full);
+/// ```
+pub fn set_reset_mode(mode: ResetMode) {
+    GRANULAR.store(mode == ResetMode::Granular, Ordering::Relaxed);
+}
+
+/// Runs `f` with styles turned off according to `mode`, for values displayed on the current
+/// thread. This takes priority over the mode set by [`set_reset_mode`], without affecting other
+/// threads or values displayed after `f` returns, so that a library can choose how its own output
+/// is displayed.
+///
+/// As with [`with_style_stack`](crate::with_style_stack), the output has to be formatted within
+/// `f`.
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, ResetMode, with_reset_mode};
+///
+/// let granular = with_reset_mode(ResetMode::Granular, || "text".bold().to_string());
+/// assert_eq!(granular, "\x1b[1mtext\x1b[22m");
+/// assert_eq!("text".bold().to_string(), "\x1b[1mtext\x1b[0m");
+/// ```
+#[cfg(feature = "std")]
+pub fn with_reset_mode<T>(mode: ResetMode, f: impl FnOnce() -> T) -> T {
+    let previous = SCOPED.with(|scoped| scoped.replace(Some(mode)));
+    let _guard = RestoreModeGuard { previous };
+
+    f()
+}
+
+#[cfg(feature = "std")]
+struct RestoreModeGuard {
+    previous: Option<ResetMode>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for RestoreModeGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+/// Returns how styles are turned off at the end of the text they apply to on the current thread.
+/// See [`set_reset_mode`] and `with_reset_mode`.
+pub fn reset_mode() -> ResetMode {
+    if is_granular() {
+        ResetMode::Granular
+    } else {
+        ResetMode::Full
+    }
+}
+
+pub(crate) fn is_granular() -> bool {
+    #[cfg(feature = "std")]
+    if let Ok(Some(mode)) = SCOPED.try_with(core::cell::Cell::get) {
+        return mode == ResetMode::Granular;
+    }
+    GRANULAR.load(Ordering::Relaxed)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{AnsiColors, Font, OwoColorize, Style, StyledList};
+    use std::{format, string::ToString};
+
+    fn granular(value: impl core::fmt::Display) -> std::string::String {
+        with_reset_mode(ResetMode::Granular, || value.to_string())
+    }
+
+    #[test]
+    fn test_granular_effects() {
+        assert_eq!(granular("a".bold()), "\x1b[1ma\x1b[22m");
+        assert_eq!(granular("a".dimmed()), "\x1b[2ma\x1b[22m");
+        assert_eq!(granular("a".italic()), "\x1b[3ma\x1b[23m");
+        assert_eq!(granular("a".fraktur()), "\x1b[20ma\x1b[23m");
+        assert_eq!(granular("a".underline()), "\x1b[4ma\x1b[24m");
+        assert_eq!(granular("a".curly_underline()), "\x1b[4:3ma\x1b[24m");
+        assert_eq!(granular("a".blink()), "\x1b[5ma\x1b[25m");
+        assert_eq!(granular("a".blink_fast()), "\x1b[6ma\x1b[25m");
+        assert_eq!(granular("a".reversed()), "\x1b[7ma\x1b[27m");
+        assert_eq!(granular("a".hidden()), "\x1b[8ma\x1b[28m");
+        assert_eq!(granular("a".strikethrough()), "\x1b[9ma\x1b[29m");
+        assert_eq!(granular("a".framed()), "\x1b[51ma\x1b[54m");
+        assert_eq!(granular("a".encircled()), "\x1b[52ma\x1b[54m");
+        assert_eq!(granular("a".overline()), "\x1b[53ma\x1b[55m");
+        assert_eq!(granular("a".superscript()), "\x1b[73ma\x1b[75m");
+        assert_eq!(granular("a".subscript()), "\x1b[74ma\x1b[75m");
+        assert_eq!(granular("a".font(Font::Alt3)), "\x1b[13ma\x1b[10m");
+    }
+
+    #[test]
+    fn test_granular_colors() {
+        assert_eq!(granular("a".red().on_blue()), "\x1b[31;44ma\x1b[39;49m");
+        assert_eq!(
+            granular("a".color(AnsiColors::Red).on_color(AnsiColors::Blue)),
+            "\x1b[31;44ma\x1b[39;49m"
+        );
+        // Colors on their own are always turned off on their own
+        assert_eq!(granular("a".red()), "\x1b[31ma\x1b[39m");
+        assert_eq!(
+            granular(Style::new().green().on_black().italic().style("a")),
+            "\x1b[32;40;3ma\x1b[39;49;23m"
+        );
+        assert_eq!(
+            granular(StyledList::from([
+                Style::new().bold().style("a"),
+                Style::new().style("b"),
+            ])),
+            "\x1b[1ma\x1b[22mb"
+        );
+    }
+
+    #[test]
+    fn test_with_reset_mode() {
+        // Nested styles keep the enclosing style
+        let inner = granular("inner".italic());
+        assert_eq!(
+            granular(format!("{inner} tail").bold()),
+            "\x1b[1m\x1b[3minner\x1b[23m tail\x1b[22m"
+        );
+
+        assert_eq!(reset_mode(), ResetMode::Full);
+        with_reset_mode(ResetMode::Granular, || {
+            assert_eq!(reset_mode(), ResetMode::Granular);
+            with_reset_mode(ResetMode::Full, || {
+                assert_eq!("a".bold().to_string(), "\x1b[1ma\x1b[0m");
+            });
+            assert_eq!(reset_mode(), ResetMode::Granular);
+
+            // Other threads aren't affected
+            let other = std::thread::spawn(|| "a".bold().to_string());
+            assert_eq!(other.join().unwrap(), "\x1b[1ma\x1b[0m");
+        });
+        assert_eq!(reset_mode(), ResetMode::Full);
+    }
+}
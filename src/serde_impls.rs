@@ -0,0 +1,495 @@
+//! `Serialize` and `Deserialize` implementations for styles, effects and colors.
+//!
+//! Everything is serialized as a string in the form parsed by its `FromStr` implementation, such
+//! as `"bold italic #ff8800 on black"` for a [`Style`] and `"bright-red"` for a color. When
+//! deserializing, colors also accept RGB channels as `[255, 136, 0]` or
+//! `{ r = 255, g = 136, b = 0 }` and xterm colors their index as a number, and styles accept a
+//! map such as `{ fg = "red", bg = "black", underline_color = "blue", effects = ["bold"] }`.
+//!
+//! The other forms need a self-describing format, so formats which aren't human-readable, such as
+//! bincode and postcard, only accept the string form which values are serialized as.
+use crate::{AnsiColors, CssColors, DynColors, Effect, EffectSet, Rgb, Style, XtermColors};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+macro_rules! impl_serialize_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_display!(DynColors, Effect, Style);
+
+impl Serialize for AnsiColors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&DynColors::Ansi(*self))
+    }
+}
+
+impl Serialize for CssColors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl Serialize for XtermColors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl Serialize for Rgb {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&DynColors::Rgb(self.0, self.1, self.2))
+    }
+}
+
+impl Serialize for EffectSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for effect in self {
+            seq.serialize_element(&effect)?;
+        }
+        seq.end()
+    }
+}
+
+/// Defines an enum of the field names of a map, deserialized from their names.
+macro_rules! fields {
+    ($name:ident { $($field:ident = $text:literal),* $(,)? }) => {
+        #[allow(non_camel_case_types)]
+        enum $name {
+            $($field,)*
+        }
+
+        impl $name {
+            const NAMES: &'static [&'static str] = &[$($text,)*];
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str("a field name")
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$name, E> {
+                        match v {
+                            $($text => Ok($name::$field),)*
+                            _ => Err(E::unknown_field(v, $name::NAMES)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+    };
+}
+
+fields!(RgbField { r = "r", g = "g", b = "b" });
+fields!(StyleField {
+    fg = "fg",
+    bg = "bg",
+    underline_color = "underline_color",
+    effects = "effects",
+});
+
+/// Deserializes a color from any of its forms: a string, an xterm index, or RGB channels as a
+/// sequence or a map.
+struct ColorVisitor;
+
+impl ColorVisitor {
+    fn xterm_index<E: de::Error>(self, index: i128) -> Result<DynColors, E> {
+        match u8::try_from(index) {
+            Ok(index) => Ok(DynColors::Xterm(XtermColors::from(index))),
+            Err(_) => Err(match i64::try_from(index) {
+                Ok(index) => E::invalid_value(Unexpected::Signed(index), &self),
+                Err(_) => E::invalid_value(Unexpected::Other("integer"), &self),
+            }),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = DynColors;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a color name, a hex color, an xterm index from 0 to 255 or RGB channels")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<DynColors, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<DynColors, E> {
+        self.xterm_index(v.into())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<DynColors, E> {
+        self.xterm_index(v.into())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DynColors, A::Error> {
+        let mut channels = [0; 3];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            *channel = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &"3 RGB channels"))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &"3 RGB channels"));
+        }
+        Ok(DynColors::Rgb(channels[0], channels[1], channels[2]))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DynColors, A::Error> {
+        let mut channels = [None; 3];
+        while let Some(field) = map.next_key::<RgbField>()? {
+            let (i, name) = match field {
+                RgbField::r => (0, "r"),
+                RgbField::g => (1, "g"),
+                RgbField::b => (2, "b"),
+            };
+            if channels[i].is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+            channels[i] = Some(map.next_value()?);
+        }
+
+        let channel =
+            |i: usize| channels[i].ok_or_else(|| de::Error::missing_field(RgbField::NAMES[i]));
+        Ok(DynColors::Rgb(channel(0)?, channel(1)?, channel(2)?))
+    }
+}
+
+impl<'de> Deserialize<'de> for DynColors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_str(ColorVisitor)
+        }
+    }
+}
+
+/// Accepts any color which can be converted to RGB, including named colors.
+impl<'de> Deserialize<'de> for Rgb {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        DynColors::deserialize(deserializer)?
+            .to_rgb()
+            .ok_or_else(|| de::Error::custom("the default color has no RGB value"))
+    }
+}
+
+/// Accepts a name or an index, such as `"FlushOrange"` or `208`.
+impl<'de> Deserialize<'de> for XtermColors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct XtermVisitor;
+
+        impl<'de> Visitor<'de> for XtermVisitor {
+            type Value = XtermColors;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an xterm color name or an index from 0 to 255")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<XtermColors, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<XtermColors, E> {
+                u8::try_from(v)
+                    .map(XtermColors::from)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<XtermColors, E> {
+                u8::try_from(v)
+                    .map(XtermColors::from)
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(XtermVisitor)
+        } else {
+            deserializer.deserialize_str(XtermVisitor)
+        }
+    }
+}
+
+/// Deserializes a value from a string using its `FromStr` implementation.
+struct FromStrVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T: core::str::FromStr> Visitor<'de> for FromStrVisitor<T>
+where
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for CssColors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor("a CSS color name", PhantomData))
+    }
+}
+
+impl<'de> Deserialize<'de> for Effect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor("an effect name", PhantomData))
+    }
+}
+
+/// Accepts the name of an ANSI color, such as `"bright-red"`.
+impl<'de> Deserialize<'de> for AnsiColors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.deserialize_str(FromStrVisitor("an ANSI color name", PhantomData))? {
+            DynColors::Ansi(ansi) => Ok(ansi),
+            _ => Err(de::Error::custom("expected an ANSI color name")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EffectSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EffectSetVisitor;
+
+        impl<'de> Visitor<'de> for EffectSetVisitor {
+            type Value = EffectSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a list of effects")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<EffectSet, A::Error> {
+                let mut effects = EffectSet::new();
+                while let Some(effect) = seq.next_element()? {
+                    effects.insert(effect);
+                }
+                Ok(effects)
+            }
+        }
+
+        deserializer.deserialize_seq(EffectSetVisitor)
+    }
+}
+
+/// Accepts a string such as `"bold italic #ff8800 on black"`, or a map with the optional fields
+/// `fg`, `bg`, `underline_color` and `effects`.
+impl<'de> Deserialize<'de> for Style {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StyleVisitor;
+
+        impl<'de> Visitor<'de> for StyleVisitor {
+            type Value = Style;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a style string or a map of colors and effects")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Style, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Style, A::Error> {
+                let mut style = Style::new();
+                let mut effects = None;
+                let mut seen = [false; 3];
+                while let Some(field) = map.next_key::<StyleField>()? {
+                    let (color, i, name) = match field {
+                        StyleField::fg => (&mut style.fg, 0, "fg"),
+                        StyleField::bg => (&mut style.bg, 1, "bg"),
+                        StyleField::underline_color => {
+                            (&mut style.underline_color, 2, "underline_color")
+                        }
+                        StyleField::effects => {
+                            if effects.is_some() {
+                                return Err(de::Error::duplicate_field("effects"));
+                            }
+                            effects = Some(map.next_value::<EffectSet>()?);
+                            continue;
+                        }
+                    };
+                    if seen[i] {
+                        return Err(de::Error::duplicate_field(name));
+                    }
+                    seen[i] = true;
+                    *color = map.next_value()?;
+                }
+
+                for effect in effects.unwrap_or_default() {
+                    style = style.effect(effect);
+                }
+                Ok(style)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StyleVisitor)
+        } else {
+            deserializer.deserialize_str(StyleVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Font;
+    use serde_json::{from_str, json, to_value};
+
+    #[test]
+    fn test_serialize() {
+        let style = Style::new()
+            .bold()
+            .curly_underline()
+            .truecolor(255, 136, 0)
+            .on_black();
+        assert_eq!(
+            to_value(style).unwrap(),
+            json!("bold curly-underline #ff8800 on black")
+        );
+        assert_eq!(
+            to_value([
+                DynColors::Ansi(AnsiColors::BrightRed),
+                DynColors::Xterm(XtermColors::FlushOrange),
+            ])
+            .unwrap(),
+            json!(["bright-red", "208"])
+        );
+        assert_eq!(to_value(AnsiColors::Default).unwrap(), json!("default"));
+        assert_eq!(to_value(CssColors::Red).unwrap(), json!("Red"));
+        assert_eq!(
+            to_value(XtermColors::FlushOrange).unwrap(),
+            json!("FlushOrange")
+        );
+        assert_eq!(to_value(Rgb(1, 2, 3)).unwrap(), json!("#010203"));
+        assert_eq!(to_value(Effect::Font(Font::Alt2)).unwrap(), json!("font-2"));
+        assert_eq!(
+            to_value(style.effect_set()).unwrap(),
+            json!(["bold", "curly-underline"])
+        );
+    }
+
+    #[test]
+    fn test_deserialize_colors() {
+        let rgb = DynColors::Rgb(255, 136, 0);
+        assert_eq!(from_str::<DynColors>(r##""#ff8800""##).unwrap(), rgb);
+        assert_eq!(from_str::<DynColors>("[255, 136, 0]").unwrap(), rgb);
+        assert_eq!(
+            from_str::<DynColors>(r#"{"b": 0, "g": 136, "r": 255}"#).unwrap(),
+            rgb
+        );
+        assert_eq!(
+            from_str::<DynColors>("208").unwrap(),
+            DynColors::Xterm(XtermColors::FlushOrange)
+        );
+        assert_eq!(from_str::<Rgb>(r#""white""#).unwrap(), Rgb(192, 192, 192));
+        assert_eq!(
+            from_str::<XtermColors>(r#""flush orange""#).unwrap(),
+            XtermColors::FlushOrange
+        );
+        assert_eq!(
+            from_str::<XtermColors>("208").unwrap(),
+            XtermColors::FlushOrange
+        );
+        assert_eq!(
+            from_str::<AnsiColors>(r#""BrightRed""#).unwrap(),
+            AnsiColors::BrightRed
+        );
+        assert_eq!(
+            from_str::<CssColors>(r#""rebecca-purple""#).unwrap(),
+            CssColors::RebeccaPurple
+        );
+
+        for error in ["256", "-1", "[1, 2]", "[1, 2, 3, 4]", r#"{"r": 1, "g": 2}"#] {
+            assert!(from_str::<DynColors>(error).is_err(), "{}", error);
+        }
+        assert!(from_str::<AnsiColors>(r##""#ff0000""##).is_err());
+        assert!(from_str::<Rgb>(r#""default""#).is_err());
+        assert!(from_str::<CssColors>("1").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_style() {
+        assert_eq!(
+            from_str::<Style>(r#""bold red on blue""#).unwrap(),
+            Style::new().bold().red().on_blue()
+        );
+        assert_eq!(
+            from_str::<Style>(
+                r#"{"fg": [1, 2, 3], "underline_color": "red", "effects": ["dim", "font-4"]}"#
+            )
+            .unwrap(),
+            Style::new()
+                .truecolor(1, 2, 3)
+                .underline_color(AnsiColors::Red)
+                .dimmed()
+                .font(Font::Alt4)
+        );
+        assert_eq!(from_str::<Style>(r#"{"bg": null}"#).unwrap(), Style::new());
+
+        let error = from_str::<Style>(r#""bold sparkly""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("not an effect or a color at position 5")
+        );
+        assert!(from_str::<Style>(r#"{"fg": "red", "fg": "blue"}"#).is_err());
+        assert!(from_str::<Style>(r#"{"color": "red"}"#).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_non_self_describing() {
+        fn round_trip<T>(value: T)
+        where
+            T: Serialize + for<'de> Deserialize<'de> + PartialEq + fmt::Debug,
+        {
+            let bytes = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+        }
+
+        round_trip(
+            Style::new()
+                .bold()
+                .font(Font::Alt3)
+                .color(XtermColors::FlushOrange)
+                .underline_truecolor(1, 2, 3),
+        );
+        round_trip(Style::new());
+        round_trip(DynColors::Css(CssColors::Teal));
+        round_trip(DynColors::Rgb(255, 136, 0));
+        round_trip(XtermColors::FlushOrange);
+        round_trip(AnsiColors::BrightRed);
+        round_trip(CssColors::RebeccaPurple);
+        round_trip(Rgb(1, 2, 3));
+        round_trip(Effect::CurlyUnderline);
+        round_trip(Style::new().effect_set());
+        round_trip(
+            [Effect::Bold, Effect::Hidden]
+                .into_iter()
+                .collect::<EffectSet>(),
+        );
+    }
+}
@@ -0,0 +1,386 @@
+//! Converting SGR parameter lists, such as `01;38;5;208`, into styles.
+use crate::{AnsiColors, DynColors, Effect, Font, Style, XtermColors};
+use core::fmt;
+
+/// An error for when a list of SGR parameters can not be parsed, see [`Style::from_sgr`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSgrError {
+    kind: ParseSgrErrorKind,
+    position: usize,
+}
+
+/// The reason a list of SGR parameters could not be parsed, see [`ParseSgrError::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseSgrErrorKind {
+    /// A parameter is not a number from 0 to 255
+    InvalidNumber,
+    /// A code doesn't set or remove a color or an effect supported by [`Style`]
+    UnsupportedCode,
+    /// A `38`, `48` or `58` code isn't followed by `5;n` or `2;r;g;b`
+    InvalidColor,
+}
+
+impl ParseSgrError {
+    const fn new(kind: ParseSgrErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// The reason the parameters could not be parsed
+    #[must_use]
+    pub const fn kind(&self) -> ParseSgrErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the parsed string at which the error was found
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseSgrErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseSgrErrorKind::InvalidNumber => "invalid number",
+            ParseSgrErrorKind::UnsupportedCode => "unsupported SGR code",
+            ParseSgrErrorKind::InvalidColor => "expected `5;n` or `2;r;g;b` after the color code",
+        })
+    }
+}
+
+impl fmt::Display for ParseSgrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+const ANSI_COLORS: [AnsiColors; 8] = [
+    AnsiColors::Black,
+    AnsiColors::Red,
+    AnsiColors::Green,
+    AnsiColors::Yellow,
+    AnsiColors::Blue,
+    AnsiColors::Magenta,
+    AnsiColors::Cyan,
+    AnsiColors::White,
+];
+
+const BRIGHT_ANSI_COLORS: [AnsiColors; 8] = [
+    AnsiColors::BrightBlack,
+    AnsiColors::BrightRed,
+    AnsiColors::BrightGreen,
+    AnsiColors::BrightYellow,
+    AnsiColors::BrightBlue,
+    AnsiColors::BrightMagenta,
+    AnsiColors::BrightCyan,
+    AnsiColors::BrightWhite,
+];
+
+const UNDERLINES: [Effect; 5] = [
+    Effect::Underline,
+    Effect::DoubleUnderline,
+    Effect::CurlyUnderline,
+    Effect::DottedUnderline,
+    Effect::DashedUnderline,
+];
+
+/// A parameter, along with its position in the whole string.
+type Param<'a> = (usize, &'a str);
+
+/// Splits `s`, which starts at `position` in the whole string, into parameters.
+fn params(s: &str, separator: char, position: usize) -> impl Iterator<Item = Param<'_>> + Clone {
+    s.split(separator).scan(position, |position, param| {
+        let start = *position;
+        *position += param.len() + 1;
+        Some((start, param))
+    })
+}
+
+fn number((position, param): Param<'_>) -> Result<u8, ParseSgrError> {
+    if param.is_empty() {
+        // An omitted parameter is 0
+        return Ok(0);
+    }
+    match param.parse() {
+        Ok(number) if param.bytes().all(|c| c.is_ascii_digit()) => Ok(number),
+        _ => Err(ParseSgrError::new(
+            ParseSgrErrorKind::InvalidNumber,
+            position,
+        )),
+    }
+}
+
+/// Parses the color after the `38`, `48` or `58` code at `position`: `5;n` for an xterm color or
+/// `2;r;g;b` for RGB. With colons, the RGB channels may be preceded by a color space id, as in
+/// `2::r:g:b`.
+fn extended_color<'a>(
+    position: usize,
+    params: &mut (impl Iterator<Item = Param<'a>> + Clone),
+    colons: bool,
+) -> Result<DynColors, ParseSgrError> {
+    let error = |position| ParseSgrError::new(ParseSgrErrorKind::InvalidColor, position);
+    let mut next = || params.next().ok_or(error(position));
+
+    let mode = next()?;
+    match number(mode)? {
+        5 => Ok(DynColors::Xterm(XtermColors::from(number(next()?)?))),
+        2 => {
+            let mut channels = [next()?, next()?, next()?];
+            if colons {
+                if let Ok(blue) = next() {
+                    channels = [channels[1], channels[2], blue];
+                }
+            }
+            let [r, g, b] = channels;
+            Ok(DynColors::Rgb(number(r)?, number(g)?, number(b)?))
+        }
+        _ => Err(error(mode.0)),
+    }
+}
+
+/// Applies the SGR parameter `param` at `position` to `style`, taking the parameters of extended
+/// colors from `params`.
+fn apply_param<'a>(
+    mut style: Style,
+    (position, param): Param<'a>,
+    params: &mut (impl Iterator<Item = Param<'a>> + Clone),
+) -> Result<Style, ParseSgrError> {
+    let unsupported = ParseSgrError::new(ParseSgrErrorKind::UnsupportedCode, position);
+
+    if param.contains(':') {
+        let mut sub_params = self::params(param, ':', position);
+        let code = number(sub_params.next().ok_or(unsupported)?)?;
+        match code {
+            38 | 48 | 58 => {
+                let color = extended_color(position, &mut sub_params, true)?;
+                if let Some((extra, _)) = sub_params.next() {
+                    return Err(ParseSgrError::new(ParseSgrErrorKind::InvalidColor, extra));
+                }
+                match code {
+                    38 => style.fg = Some(color),
+                    48 => style.bg = Some(color),
+                    _ => style.underline_color = Some(color),
+                }
+            }
+            4 => {
+                let kind = sub_params.next().ok_or(unsupported)?;
+                if sub_params.next().is_some() {
+                    return Err(unsupported);
+                }
+                style = style.remove_effects(&UNDERLINES);
+                match usize::from(number(kind)?) {
+                    0 => {}
+                    kind => {
+                        let underline = UNDERLINES.get(kind - 1).ok_or(ParseSgrError::new(
+                            ParseSgrErrorKind::UnsupportedCode,
+                            position,
+                        ))?;
+                        style = style.effect(*underline);
+                    }
+                }
+            }
+            _ => return Err(unsupported),
+        }
+        return Ok(style);
+    }
+
+    let code = number((position, param))?;
+    style = match code {
+        0 => Style::new(),
+        1 => style.bold(),
+        2 => style.dimmed(),
+        3 => style.italic(),
+        4 => style.underline(),
+        5 => style.blink(),
+        6 => style.blink_fast(),
+        7 => style.reversed(),
+        8 => style.hidden(),
+        9 => style.strikethrough(),
+        10..=19 => style.font(Font::ALL[(code - 10) as usize]),
+        20 => style.fraktur(),
+        21 => style.double_underline(),
+        22 => style.remove_effects(&[Effect::Bold, Effect::Dimmed]),
+        23 => style.remove_effects(&[Effect::Italic, Effect::Fraktur]),
+        24 => style.remove_effects(&UNDERLINES),
+        25 => style.remove_effects(&[Effect::Blink, Effect::BlinkFast]),
+        27 => style.remove_effect(Effect::Reversed),
+        28 => style.remove_effect(Effect::Hidden),
+        29 => style.remove_effect(Effect::Strikethrough),
+        30..=37 => style.color(ANSI_COLORS[usize::from(code - 30)]),
+        38 => style.color(extended_color(position, params, false)?),
+        39 => style.color(AnsiColors::Default),
+        40..=47 => style.on_color(ANSI_COLORS[usize::from(code - 40)]),
+        48 => style.on_color(extended_color(position, params, false)?),
+        49 => style.on_color(AnsiColors::Default),
+        51 => style.framed(),
+        52 => style.encircled(),
+        53 => style.overline(),
+        54 => style.remove_effects(&[Effect::Framed, Effect::Encircled]),
+        55 => style.remove_effect(Effect::Overline),
+        58 => style.underline_color(extended_color(position, params, false)?),
+        59 => style.underline_color(AnsiColors::Default),
+        73 => style.superscript(),
+        74 => style.subscript(),
+        75 => style.remove_effects(&[Effect::Superscript, Effect::Subscript]),
+        90..=97 => style.color(BRIGHT_ANSI_COLORS[usize::from(code - 90)]),
+        100..=107 => style.on_color(BRIGHT_ANSI_COLORS[usize::from(code - 100)]),
+        _ => return Err(unsupported),
+    };
+    Ok(style)
+}
+
+/// Applies the SGR parameters in `codes`, separated by `;`, to `style`.
+pub(crate) fn apply_sgr(mut style: Style, codes: &str) -> Result<Style, ParseSgrError> {
+    let mut params = params(codes, ';', 0);
+    while let Some(param) = params.next() {
+        style = apply_param(style, param, &mut params)?;
+    }
+    Ok(style)
+}
+
+/// Applies the SGR parameters in `codes` to `style` like a terminal would, skipping the
+/// parameters which aren't supported.
+pub(crate) fn apply_sgr_lossy(mut style: Style, codes: &str) -> Style {
+    let mut params = params(codes, ';', 0);
+    while let Some(param) = params.next() {
+        style = apply_param(style, param, &mut params).unwrap_or(style);
+    }
+    style
+}
+
+impl Style {
+    /// Parses a list of SGR parameters separated by `;`, such as `01;38;5;208`, into a style.
+    /// This is the inverse of [`Style::fmt_prefix`], without the `\x1b[` and `m` around the
+    /// parameters, and is the format used by environment variables such as `GREP_COLORS` and
+    /// `LESS_TERMCAP_*`.
+    ///
+    /// The codes are applied in order, so `0` removes everything set before it and codes such as
+    /// `22` (neither bold nor dimmed) remove effects. Colors can be given as 16 color codes, as
+    /// xterm colors (`38;5;n`) or as RGB (`38;2;r;g;b`), using either `;` or `:` to separate
+    /// their parameters, and likewise for background (`48`) and underline (`58`) colors.
+    ///
+    /// ```rust
+    /// use owo_colors::{Font, ParseSgrErrorKind, Style, XtermColors};
+    ///
+    /// assert_eq!(
+    ///     Style::from_sgr("01;38;5;208"),
+    ///     Ok(Style::new().bold().color(XtermColors::FlushOrange)),
+    /// );
+    /// assert_eq!(
+    ///     Style::from_sgr("4:3;58:2::255:0:0;48;2;0;0;0"),
+    ///     Ok(Style::new()
+    ///         .curly_underline()
+    ///         .underline_truecolor(255, 0, 0)
+    ///         .on_truecolor(0, 0, 0)),
+    /// );
+    ///
+    /// let style = Style::new().red().on_blue().italic().font(Font::Alt2);
+    /// assert_eq!(style.prefix_formatter().to_string(), "\x1b[31;44;3;12m");
+    /// assert_eq!(Style::from_sgr("31;44;3;12"), Ok(style));
+    ///
+    /// let error = Style::from_sgr("01;26").unwrap_err();
+    /// assert_eq!(error.kind(), ParseSgrErrorKind::UnsupportedCode);
+    /// assert_eq!(error.position(), 3);
+    /// ```
+    pub fn from_sgr(codes: &str) -> Result<Style, ParseSgrError> {
+        apply_sgr(Style::new(), codes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CssColors, Rgb};
+    use std::string::ToString;
+
+    /// Returns the parameters written by `style.fmt_prefix`.
+    fn prefix_codes(style: Style) -> std::string::String {
+        let prefix = style.prefix_formatter().to_string();
+        prefix
+            .strip_prefix("\x1b[")
+            .and_then(|prefix| prefix.strip_suffix('m'))
+            .unwrap_or_default()
+            .into()
+    }
+
+    #[test]
+    fn test_from_sgr_round_trip() {
+        let styles = [
+            Style::new(),
+            Style::new().bold().dimmed().blink_fast().font(Font::Alt7),
+            Style::new().on_default_color().strikethrough().fraktur(),
+            Style::new()
+                .truecolor(1, 2, 3)
+                .on_color(XtermColors::FlushOrange)
+                .underline_truecolor(9, 8, 7)
+                .dashed_underline()
+                .subscript()
+                .hidden(),
+            Style::new()
+                .bright_cyan()
+                .on_bright_black()
+                .encircled()
+                .reversed()
+                .overline()
+                .double_underline()
+                .font(Font::Primary),
+        ];
+        for style in styles {
+            assert_eq!(
+                Style::from_sgr(&prefix_codes(style)),
+                Ok(style),
+                "{:?}",
+                style
+            );
+        }
+
+        // ANSI underline colors are written as xterm colors, and CSS colors as RGB
+        assert_eq!(
+            Style::from_sgr(&prefix_codes(Style::new().underline_color(AnsiColors::Red))),
+            Ok(Style::new().underline_color(XtermColors::from(1)))
+        );
+        let css = Style::new().color(CssColors::RebeccaPurple);
+        let Rgb(r, g, b) = CssColors::RebeccaPurple.rgb();
+        assert_eq!(
+            Style::from_sgr(&prefix_codes(css)),
+            Ok(Style::new().truecolor(r, g, b))
+        );
+    }
+
+    #[test]
+    fn test_from_sgr() {
+        assert_eq!(Style::from_sgr(""), Ok(Style::new()));
+        assert_eq!(Style::from_sgr("1;;31"), Ok(Style::new().red()));
+        assert_eq!(
+            Style::from_sgr("1;2;3;4:3;9;22;23;4:0;38:5:1;48:2:1:2:3"),
+            Ok(Style::new()
+                .strikethrough()
+                .color(XtermColors::from(1))
+                .on_truecolor(1, 2, 3))
+        );
+        assert_eq!(
+            Style::from_sgr("52;51;73;74;75;54;51;21"),
+            Ok(Style::new().framed().double_underline())
+        );
+    }
+
+    #[test]
+    fn test_from_sgr_errors() {
+        use ParseSgrErrorKind::*;
+
+        let error = |s: &str| {
+            let error = Style::from_sgr(s).unwrap_err();
+            (error.kind(), error.position())
+        };
+        assert_eq!(error("1;x"), (InvalidNumber, 2));
+        assert_eq!(error("1;256"), (InvalidNumber, 2));
+        assert_eq!(error("1;+1"), (InvalidNumber, 2));
+        assert_eq!(error("1;60"), (UnsupportedCode, 2));
+        assert_eq!(error("4:6"), (UnsupportedCode, 0));
+        assert_eq!(error("38;5"), (InvalidColor, 0));
+        assert_eq!(error("1;38;3;1"), (InvalidColor, 5));
+        assert_eq!(error("48;2;1;2;300"), (InvalidNumber, 9));
+        assert_eq!(error("58:5:1:2"), (InvalidColor, 7));
+    }
+}
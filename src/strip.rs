@@ -0,0 +1,137 @@
+//! Removing escape sequences from text.
+use crate::escapes::EscapeScanner;
+use core::fmt::{self, Display, Write};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+/// Displays a value without any of the escape sequences it writes, such as colors, effects,
+/// cursor movements and hyperlinks, for writing the same output to a terminal and a plain text
+/// log. The text is filtered as it's written, without allocating.
+///
+/// Control sequences (`ESC [ ...`), control strings ending in BEL or ST (such as OSC 8
+/// hyperlinks), other escape sequences and their 8-bit C1 equivalents are removed. Formatting
+/// flags such as width and alignment aren't passed on to the inner value.
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, Stripped};
+///
+/// let message = format!("{}: {}", "error".red().bold(), "file not found".hyperlink("file:///"));
+/// assert_eq!(Stripped(&message).to_string(), "error: file not found");
+/// assert_eq!(Stripped("ok".green()).to_string(), "ok");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stripped<T>(pub T);
+
+impl<T: Display> Display for Stripped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            StripWriter {
+                inner: f,
+                escapes: EscapeScanner::default(),
+            },
+            "{}",
+            self.0
+        )
+    }
+}
+
+/// Removes escape sequences from a string, borrowing it if it doesn't contain any.
+///
+/// See [`Stripped`] for the sequences which are removed, and to remove them from any
+/// [`Display`] value.
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, strip};
+/// use std::borrow::Cow;
+///
+/// assert_eq!(strip(&"warning".yellow().to_string()), "warning");
+/// assert!(matches!(strip("plain"), Cow::Borrowed("plain")));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip(s: &str) -> Cow<'_, str> {
+    if !s.contains(|c| EscapeScanner::default().feed(c)) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut stripped = String::with_capacity(s.len());
+    let _ = StripWriter {
+        inner: &mut stripped,
+        escapes: EscapeScanner::default(),
+    }
+    .write_str(s);
+    Cow::Owned(stripped)
+}
+
+/// Writes text to another writer without its escape sequences.
+struct StripWriter<W> {
+    inner: W,
+    escapes: EscapeScanner,
+}
+
+impl<W: Write> Write for StripWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Write runs of visible text at once, rather than one character at a time
+        let mut start = None;
+        for (i, c) in s.char_indices() {
+            match (self.escapes.feed(c), start) {
+                (true, Some(run_start)) => {
+                    self.inner.write_str(&s[run_start..i])?;
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        match start {
+            Some(run_start) => self.inner.write_str(&s[run_start..]),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OwoColorize, Style};
+    use std::{format, string::ToString};
+
+    /// Writes one character at a time, as the escape sequences may be split across writes
+    struct Chars<T>(T);
+
+    impl<T: Display> Display for Chars<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for c in self.0.to_string().chars() {
+                f.write_char(c)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_stripped() {
+        let styled = format!(
+            "{} {} {}",
+            "red".red().on_bright_blue(),
+            "link".hyperlink("https://example.com").italic(),
+            Style::new().underline_truecolor(1, 2, 3).style("é"),
+        );
+        assert_eq!(Stripped(&styled).to_string(), "red link é");
+        assert_eq!(Stripped(Chars(&styled)).to_string(), "red link é");
+        assert_eq!(
+            Stripped("\x1b]0;title\x07a\x1b(Bb\u{9b}2Jc\x1b[").to_string(),
+            "abc"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strip() {
+        assert!(matches!(strip(""), Cow::Borrowed("")));
+        assert!(matches!(strip("plain ✓"), Cow::Borrowed("plain ✓")));
+        assert!(matches!(strip("\x1b[1mbold\x1b[0m"), Cow::Owned(s) if s == "bold"));
+        assert_eq!(strip("\x1b[31m\x1b]8;;x\x1b\\\x1b[0m"), "");
+    }
+}
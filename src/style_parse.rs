@@ -0,0 +1,474 @@
+//! Reading and writing styles as text, such as `bold italic #ff8800 on black`.
+use crate::dyn_colors::name_matches;
+use crate::{DynColors, Effect, Font, ParseColorErrorKind, Style};
+use core::fmt;
+use core::ops::Range;
+
+/// An error for when a [`Style`] or an [`Effect`] can not be parsed from a string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    kind: ParseStyleErrorKind,
+    position: usize,
+    word: Range<usize>,
+}
+
+/// The reason a style could not be parsed, see [`ParseStyleError::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseStyleErrorKind {
+    /// A word is neither an effect nor a color
+    UnknownWord,
+    /// The string is not the name of an effect
+    UnknownEffect,
+    /// A font number is above 9
+    InvalidFont,
+    /// A word looks like a color, but could not be parsed as one
+    InvalidColor(ParseColorErrorKind),
+    /// `on` or `underline-color` is not followed by a color
+    ExpectedColor,
+    /// There are more than two colors without `on`, or the background or underline color is given
+    /// twice
+    TooManyColors,
+}
+
+impl ParseStyleError {
+    const fn new(kind: ParseStyleErrorKind, word: Range<usize>) -> Self {
+        Self {
+            kind,
+            position: word.start,
+            word,
+        }
+    }
+
+    /// The reason the style could not be parsed
+    #[must_use]
+    pub const fn kind(&self) -> ParseStyleErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the parsed string at which the error was found
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The byte range of the word containing the error, for example to underline it in an error
+    /// message. This is empty and at the end of the string if a color is missing at the end.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.word.clone()
+    }
+}
+
+impl fmt::Display for ParseStyleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseStyleErrorKind::UnknownWord => f.write_str("not an effect or a color"),
+            ParseStyleErrorKind::UnknownEffect => f.write_str("unknown effect"),
+            ParseStyleErrorKind::InvalidFont => f.write_str("fonts are numbered from 0 to 9"),
+            ParseStyleErrorKind::InvalidColor(kind) => write!(f, "invalid color: {}", kind),
+            ParseStyleErrorKind::ExpectedColor => f.write_str("expected a color"),
+            ParseStyleErrorKind::TooManyColors => f.write_str("too many colors"),
+        }
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+/// The names of the effects. When an effect has several names, the first one is used to display
+/// it.
+const EFFECT_NAMES: [(&str, Effect); 23] = [
+    ("bold", Effect::Bold),
+    ("dimmed", Effect::Dimmed),
+    ("dim", Effect::Dimmed),
+    ("italic", Effect::Italic),
+    ("underline", Effect::Underline),
+    ("ul", Effect::Underline),
+    ("blink", Effect::Blink),
+    ("blink-fast", Effect::BlinkFast),
+    ("reversed", Effect::Reversed),
+    ("reverse", Effect::Reversed),
+    ("hidden", Effect::Hidden),
+    ("strikethrough", Effect::Strikethrough),
+    ("strike", Effect::Strikethrough),
+    ("double-underline", Effect::DoubleUnderline),
+    ("curly-underline", Effect::CurlyUnderline),
+    ("dotted-underline", Effect::DottedUnderline),
+    ("dashed-underline", Effect::DashedUnderline),
+    ("overline", Effect::Overline),
+    ("framed", Effect::Framed),
+    ("encircled", Effect::Encircled),
+    ("superscript", Effect::Superscript),
+    ("subscript", Effect::Subscript),
+    ("fraktur", Effect::Fraktur),
+];
+
+/// Parses `word` as an effect, returning `None` if it isn't the name of one.
+fn parse_effect(word: &str) -> Option<Result<Effect, ParseStyleErrorKind>> {
+    if let Some((_, effect)) = EFFECT_NAMES
+        .iter()
+        .find(|(name, _)| name_matches(word, name))
+    {
+        return Some(Ok(*effect));
+    }
+
+    let font = word
+        .get(..4)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("font"))
+        .map(|_| word[4..].trim_start_matches(['-', '_']))
+        .filter(|font| !font.is_empty() && font.bytes().all(|c| c.is_ascii_digit()))?;
+    Some(
+        font.parse::<u8>()
+            .ok()
+            .and_then(Font::new)
+            .map(Effect::Font)
+            .ok_or(ParseStyleErrorKind::InvalidFont),
+    )
+}
+
+/// Displays the effect's name, such as `bold`, `blink-fast` or `font-3`, which can be parsed back
+/// with [`str::parse`].
+impl fmt::Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Effect::Font(font) => write!(f, "font-{}", font.number()),
+            effect => {
+                let name = EFFECT_NAMES
+                    .iter()
+                    .find(|(_, named)| named == effect)
+                    .map_or("", |(name, _)| name);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// Parses an effect from its name, such as `bold` or `blink-fast`, or an alternate font from 0 to
+/// 9 as `font-3`.
+///
+/// Names are case-insensitive, and spaces, hyphens and underscores are ignored, so `BlinkFast` is
+/// the same effect. The shorter names `dim`, `ul`, `reverse` and `strike` are also accepted, as in
+/// git's color configuration.
+///
+/// ```rust
+/// use owo_colors::{Effect, Font, ParseStyleErrorKind};
+///
+/// assert_eq!("curly_underline".parse(), Ok(Effect::CurlyUnderline));
+/// assert_eq!("ul".parse(), Ok(Effect::Underline));
+/// assert_eq!("font-3".parse(), Ok(Effect::Font(Font::Alt3)));
+/// assert_eq!(
+///     "sparkly".parse::<Effect>().unwrap_err().kind(),
+///     ParseStyleErrorKind::UnknownEffect
+/// );
+/// ```
+impl core::str::FromStr for Effect {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_start();
+        let start = s.len() - trimmed.len();
+        let word = start..start + trimmed.trim_end().len();
+
+        match parse_effect(&s[word.clone()]) {
+            Some(Ok(effect)) => Ok(effect),
+            Some(Err(kind)) => Err(ParseStyleError::new(kind, word)),
+            None => Err(ParseStyleError::new(
+                ParseStyleErrorKind::UnknownEffect,
+                word,
+            )),
+        }
+    }
+}
+
+/// Splits a string into words separated by whitespace, keeping the arguments of color functions
+/// such as `rgb(255, 136, 0)` in one word.
+#[derive(Clone)]
+struct Words<'a> {
+    s: &'a str,
+    position: usize,
+}
+
+impl Iterator for Words<'_> {
+    /// The byte range of the word
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let rest = &self.s[self.position..];
+        let start = self.position + (rest.len() - rest.trim_start().len());
+        if start == self.s.len() {
+            self.position = start;
+            return None;
+        }
+
+        let mut depth = 0_usize;
+        let end = self.s[start..]
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                depth == 0 && c.is_whitespace()
+            })
+            .map_or(self.s.len(), |(i, _)| start + i);
+        self.position = end;
+        Some(start..end)
+    }
+}
+
+/// Parses the color starting at `word`, using the next word too for names such as `bright red`.
+/// `expected` says whether something other than a color is an error.
+fn parse_color(
+    s: &str,
+    words: &mut Words<'_>,
+    mut word: Range<usize>,
+    expected: bool,
+) -> Result<DynColors, ParseStyleError> {
+    if name_matches(&s[word.clone()], "bright") {
+        if let Some(next) = words.clone().next() {
+            word.end = next.end;
+            words.next();
+        }
+    }
+
+    s[word.clone()]
+        .parse()
+        .map_err(|error: crate::ParseColorError| match error.kind() {
+            ParseColorErrorKind::UnknownName if expected => {
+                ParseStyleError::new(ParseStyleErrorKind::ExpectedColor, word)
+            }
+            ParseColorErrorKind::UnknownName => {
+                ParseStyleError::new(ParseStyleErrorKind::UnknownWord, word)
+            }
+            kind => ParseStyleError {
+                kind: ParseStyleErrorKind::InvalidColor(kind),
+                position: word.start + error.position(),
+                word,
+            },
+        })
+}
+
+/// Displays the style as its effects followed by its colors, in the form parsed by
+/// [`str::parse`], such as `bold italic #ff8800 on black`. A style which doesn't apply any
+/// formatting is displayed as `normal`.
+///
+/// CSS colors which share a name with an ANSI color are displayed as hex, so they're parsed
+/// back as RGB colors. See [the `Display` implementation of `DynColors`](DynColors#impl-Display-for-DynColors).
+///
+/// ```rust
+/// use owo_colors::{AnsiColors, Style};
+///
+/// let style = Style::new().italic().bold().truecolor(255, 136, 0).on_black();
+/// assert_eq!(style.to_string(), "bold italic #ff8800 on black");
+///
+/// let style = Style::new().curly_underline().underline_color(AnsiColors::BrightRed);
+/// assert_eq!(style.to_string(), "curly-underline underline-color bright-red");
+/// ```
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Links can't be written in this syntax
+        if self.is_sgr_plain() {
+            return f.write_str("normal");
+        }
+
+        let mut separator = "";
+        for effect in self.effect_set() {
+            write!(f, "{}{}", separator, effect)?;
+            separator = " ";
+        }
+        if let Some(fg) = self.fg {
+            write!(f, "{}{}", separator, fg)?;
+            separator = " ";
+        }
+        if let Some(bg) = self.bg {
+            write!(f, "{}on {}", separator, bg)?;
+            separator = " ";
+        }
+        if let Some(underline_color) = self.underline_color {
+            write!(f, "{}underline-color {}", separator, underline_color)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a style from a list of effects and colors separated by whitespace, in any order, such
+/// as `bold italic #ff8800 on black`. This is the syntax of git's `color.*` settings, extended
+/// with the other effects and color formats supported by owo-colors.
+///
+/// * Effects are parsed as by [`Effect`'s `FromStr` implementation](Effect#impl-FromStr-for-Effect).
+/// * Colors are parsed as by [`DynColors`'s `FromStr` implementation](DynColors#impl-FromStr-for-DynColors),
+///   with `bright red` written either as one word or two.
+/// * The first color is the foreground color and the second the background color. A color after
+///   `on` is always the background color, and a color after `underline-color` colors the
+///   underline.
+/// * `normal` takes the place of a color without setting it, so `normal blue` only sets the
+///   background. An empty string or `normal` on its own is a style without any formatting.
+///
+/// ```rust
+/// use owo_colors::{AnsiColors, ParseStyleErrorKind, Style};
+///
+/// assert_eq!(
+///     "bold italic #ff8800 on black".parse(),
+///     Ok(Style::new().bold().italic().truecolor(255, 136, 0).on_black()),
+/// );
+/// assert_eq!("red blue".parse(), Ok(Style::new().red().on_blue()));
+/// assert_eq!("normal bright red".parse(), Ok(Style::new().on_bright_red()));
+/// assert_eq!(
+///     "ul underline-color rgb(255, 0, 0)".parse(),
+///     Ok(Style::new().underline().underline_truecolor(255, 0, 0)),
+/// );
+///
+/// let error = "bold shiny red".parse::<Style>().unwrap_err();
+/// assert_eq!(error.kind(), ParseStyleErrorKind::UnknownWord);
+/// assert_eq!(error.span(), 5..10);
+/// assert_eq!(error.to_string(), "not an effect or a color at position 5");
+/// ```
+impl core::str::FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::new();
+        // Whether the foreground and background colors have been given, possibly as `normal`
+        let mut fg = false;
+        let mut bg = false;
+
+        let mut words = Words { s, position: 0 };
+        while let Some(word) = words.next() {
+            let text = &s[word.clone()];
+            if text.eq_ignore_ascii_case("on") || name_matches(text, "underline-color") {
+                let on = text.eq_ignore_ascii_case("on");
+                let color = words
+                    .next()
+                    .ok_or(ParseStyleError::new(
+                        ParseStyleErrorKind::ExpectedColor,
+                        s.len()..s.len(),
+                    ))
+                    .and_then(|color| parse_color(s, &mut words, color, true))?;
+                if on && !bg {
+                    style.bg = Some(color);
+                    bg = true;
+                } else if !on && style.underline_color.is_none() {
+                    style.underline_color = Some(color);
+                } else {
+                    return Err(ParseStyleError::new(
+                        ParseStyleErrorKind::TooManyColors,
+                        word.start..words.position,
+                    ));
+                }
+            } else if let Some(effect) = parse_effect(text) {
+                let effect = effect.map_err(|kind| ParseStyleError::new(kind, word))?;
+                style = style.effect(effect);
+            } else {
+                let color = if text.eq_ignore_ascii_case("normal") {
+                    None
+                } else {
+                    Some(parse_color(s, &mut words, word.clone(), false)?)
+                };
+                if !fg {
+                    style.fg = color;
+                    fg = true;
+                } else if !bg {
+                    style.bg = color;
+                    bg = true;
+                } else {
+                    return Err(ParseStyleError::new(
+                        ParseStyleErrorKind::TooManyColors,
+                        word.start..words.position,
+                    ));
+                }
+            }
+        }
+
+        Ok(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnsiColors, CssColors, XtermColors};
+    use std::string::ToString;
+
+    #[test]
+    fn test_style_round_trip() {
+        let styles = [
+            Style::new(),
+            Style::new().bold().dimmed().blink_fast().font(Font::Alt7),
+            Style::new().on_default_color().strikethrough().fraktur(),
+            Style::new()
+                .color(CssColors::LightSeaGreen)
+                .on_color(XtermColors::FlushOrange)
+                .underline_color(AnsiColors::BrightBlack)
+                .dashed_underline()
+                .subscript(),
+            Style::new().on_truecolor(0, 0, 0).encircled().reversed(),
+        ];
+        for style in styles {
+            assert_eq!(style.to_string().parse(), Ok(style), "{}", style);
+        }
+
+        for &(name, effect) in &EFFECT_NAMES {
+            assert_eq!(effect.to_string().parse(), Ok(effect));
+            assert_eq!(name.parse(), Ok(effect));
+        }
+        assert_eq!("FONT_0".parse(), Ok(Effect::Font(Font::Primary)));
+    }
+
+    #[test]
+    fn test_style_words() {
+        assert_eq!("".parse(), Ok(Style::new()));
+        assert_eq!(" normal ".parse(), Ok(Style::new()));
+        assert_eq!(
+            "Bright Blue on bright_white\tBOLD".parse(),
+            Ok(Style::new().bright_blue().on_bright_white().bold())
+        );
+        assert_eq!(
+            "on red  hsl(120deg 100% 25%)".parse(),
+            Ok(Style::new().on_red().truecolor(0, 128, 0))
+        );
+        assert_eq!(
+            "underline curly-underline font3 208".parse(),
+            Ok(Style::new()
+                .curly_underline()
+                .font(Font::Alt3)
+                .color(XtermColors::FlushOrange))
+        );
+    }
+
+    #[test]
+    fn test_style_errors() {
+        use ParseStyleErrorKind::*;
+
+        let error = |s: &str| {
+            let error = s.parse::<Style>().unwrap_err();
+            (error.kind(), error.position(), error.span())
+        };
+        assert_eq!(error("bold sparkly"), (UnknownWord, 5, 5..12));
+        assert_eq!(error("bold font-12"), (InvalidFont, 5, 5..12));
+        assert_eq!(
+            error("red #ff88g0"),
+            (InvalidColor(ParseColorErrorKind::InvalidHexDigit), 9, 4..11)
+        );
+        assert_eq!(error("red on"), (ExpectedColor, 6, 6..6));
+        assert_eq!(error("on bold"), (ExpectedColor, 3, 3..7));
+        assert_eq!(error("red blue green"), (TooManyColors, 9, 9..14));
+        assert_eq!(
+            error("red on blue on bright green"),
+            (TooManyColors, 12, 12..27)
+        );
+        assert_eq!(
+            error("rgb(1, 2, 3) rgb(1, 2, 3) ansi(1)"),
+            (TooManyColors, 26, 26..33)
+        );
+        assert_eq!("font-10".parse::<Effect>().unwrap_err().span(), 0..7);
+        assert_eq!(
+            " red ".parse::<Effect>().unwrap_err(),
+            ParseStyleError::new(UnknownEffect, 1..4)
+        );
+    }
+}
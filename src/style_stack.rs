@@ -0,0 +1,171 @@
+//! Restoring the enclosing style after nested [`Styled`] values.
+use crate::{Style, Styled};
+use core::cell::RefCell;
+use core::fmt;
+use std::vec::Vec;
+
+std::thread_local! {
+    /// The styles of the [`Styled`] values currently being displayed on this thread, as they
+    /// appear in the terminal (including the styles they're nested in), or `None` outside of
+    /// [`with_style_stack`].
+    static STACK: RefCell<Option<Vec<Style>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with [`Styled`] values aware of the [`Styled`] values they are nested in, on the
+/// current thread.
+///
+/// Normally a [`Styled`] value ends with a reset, so any text after it inside another [`Styled`]
+/// value loses the outer style. Inside `with_style_stack`, a nested [`Styled`] value instead ends
+/// by restoring the style of the value it's nested in. Values which aren't nested are displayed as
+/// usual.
+///
+/// This only applies while the values are being displayed inside `f`, so the output has to be
+/// formatted within it. Other ways of styling text, such as [`OwoColorize::bold`], aren't
+/// affected.
+///
+/// [`OwoColorize::bold`]: crate::OwoColorize::bold
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, Style, with_style_stack};
+///
+/// struct Field(&'static str);
+///
+/// impl std::fmt::Display for Field {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}", self.0.style(Style::new().bold()))
+///     }
+/// }
+///
+/// struct Line(Field, Field);
+///
+/// impl std::fmt::Display for Line {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{} and {}", self.0, self.1)
+///     }
+/// }
+///
+/// let line = Line(Field("a"), Field("b"));
+/// let warning = Style::new().yellow().style(&line);
+///
+/// // The yellow is lost after the first field
+/// assert_eq!(warning.to_string(), "\x1b[33m\x1b[1ma\x1b[0m and \x1b[1mb\x1b[0m\x1b[0m");
+///
+/// // The yellow is restored after each field
+/// assert_eq!(
+///     with_style_stack(|| warning.to_string()),
+///     "\x1b[33m\x1b[1ma\x1b[0m\x1b[33m and \x1b[1mb\x1b[0m\x1b[33m\x1b[0m",
+/// );
+/// ```
+pub fn with_style_stack<T>(f: impl FnOnce() -> T) -> T {
+    let previous = STACK.with(|stack| stack.replace(Some(Vec::new())));
+    let _guard = RestoreStackGuard { previous };
+
+    f()
+}
+
+struct RestoreStackGuard {
+    previous: Option<Vec<Style>>,
+}
+
+impl Drop for RestoreStackGuard {
+    fn drop(&mut self) {
+        STACK.with(|stack| *stack.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Pops the style pushed for a [`Styled`] value once it has been displayed, even if displaying it
+/// panics.
+struct PopGuard;
+
+impl Drop for PopGuard {
+    fn drop(&mut self) {
+        STACK.with(|stack| {
+            if let Some(stack) = stack.borrow_mut().as_mut() {
+                stack.pop();
+            }
+        });
+    }
+}
+
+/// Displays `styled` using `fmt_target` to display its target, if inside [`with_style_stack`].
+/// Returns `None` otherwise.
+pub(crate) fn fmt_styled<T>(
+    styled: &Styled<T>,
+    f: &mut fmt::Formatter<'_>,
+    fmt_target: impl FnOnce(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> Option<fmt::Result> {
+    let style = styled.style;
+    let enclosing = STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let stack = stack.as_mut()?;
+        let enclosing = stack.last().copied();
+        stack.push(enclosing.map_or(style, |enclosing| enclosing.patch(&style)));
+        Some(enclosing)
+    })?;
+    let guard = PopGuard;
+    let result = style
+        .fmt_prefix(f)
+        .and_then(|()| fmt_target(&styled.target, f));
+    drop(guard);
+
+    Some(result.and_then(|()| match enclosing {
+        // Reset, then restore the style this value is nested in. Restoring the enclosing link
+        // replaces this value's link, if the enclosing value has one.
+        Some(enclosing) if !style.is_plain() => {
+            if !style.is_sgr_plain() {
+                f.write_str("\x1b[0m")?;
+            }
+            if style.hyperlink.is_some() && enclosing.hyperlink.is_none() {
+                f.write_str(crate::hyperlink::LINK_END)?;
+            }
+            enclosing.fmt_prefix(f)
+        }
+        _ => style.fmt_suffix(f),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_stack() {
+        let inner = Style::new().underline().style("inner");
+        let middle = format!("{}", inner);
+        assert_eq!(middle, "\x1b[4minner\x1b[0m");
+
+        let middle = Style::new().bold().style(&inner);
+        let outer = Style::new().red().style(&middle);
+        assert_eq!(
+            with_style_stack(|| outer.to_string()),
+            "\x1b[31m\x1b[1m\x1b[4minner\x1b[0m\x1b[31;1m\x1b[0m\x1b[31m\x1b[0m"
+        );
+
+        // Plain styles don't need to restore anything
+        let outer = Style::new().red().style(Style::new().style("plain"));
+        assert_eq!(
+            with_style_stack(|| outer.to_string()),
+            "\x1b[31mplain\x1b[0m"
+        );
+
+        // The stack is only used inside `with_style_stack`
+        let outer = Style::new().red().style(&inner);
+        assert_eq!(outer.to_string(), "\x1b[31m\x1b[4minner\x1b[0m\x1b[0m");
+    }
+
+    #[test]
+    fn test_style_stack_exclusive_effects() {
+        let inner = Style::new().curly_underline().green().style("inner");
+        let outer = Style::new().underline().red().style(&inner);
+        assert_eq!(
+            with_style_stack(|| format!("{:>7}", outer)),
+            "\x1b[31;4m\x1b[32;4:3m  inner\x1b[0m\x1b[31;4m\x1b[0m"
+        );
+        assert_eq!(
+            Style::new()
+                .underline()
+                .patch(&Style::new().dotted_underline().bold()),
+            Style::new().dotted_underline().bold()
+        );
+    }
+}
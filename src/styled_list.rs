@@ -1,4 +1,4 @@
-use crate::{Style, Styled, dyn_styles::StyleFlags};
+use crate::{AnsiColors, DynColors, Style, Styled, dyn_styles::StyleFlags};
 use core::{
     fmt::{self, Display},
     marker::PhantomData,
@@ -17,7 +17,7 @@ mod sealed {
     }
 }
 
-use sealed::IsStyled;
+pub(crate) use sealed::IsStyled;
 
 impl<T: IsStyled> IsStyled for &T {
     type Inner = T::Inner;
@@ -111,20 +111,45 @@ where
 }
 
 impl<'a> Style {
-    /// Returns an enum that indicates how the transition from one style to this style should be printed
-    fn transition_from(&'a self, from: &Style) -> Transition<'a> {
+    /// Returns how to switch from text displayed with the `from` style to text displayed with
+    /// this style, using as few codes as possible. Displaying the returned [`StyleTransition`]
+    /// writes the codes.
+    ///
+    /// ```rust
+    /// use owo_colors::{Style, StyleTransition};
+    ///
+    /// let from = Style::new().red().bold();
+    /// let to = Style::new().green().bold().italic();
+    ///
+    /// let transition = to.transition_from(&from);
+    /// assert_eq!(transition, StyleTransition::Style(Style::new().green().italic()));
+    /// assert_eq!(transition.to_string(), "\x1b[32;3m");
+    ///
+    /// // Bold has to be turned off, which needs a reset
+    /// let transition = from.transition_from(&to);
+    /// assert_eq!(transition, StyleTransition::FullReset { from: &to, to: &from });
+    /// assert_eq!(transition.to_string(), "\x1b[0m\x1b[31;1m");
+    /// ```
+    pub fn transition_from(&'a self, from: &'a Style) -> StyleTransition<'a> {
         if self == from {
-            return Transition::Noop;
+            return StyleTransition::Noop;
         }
 
+        // The font is a number rather than a flag, so it's handled separately
+        let flags = self.style_flags.set_font(None);
+        let from_flags = from.style_flags.set_font(None);
+
         // Use full reset if transitioning from colored to non-colored
-        // or if previous style contains properties that are not in this style
+        // or if previous style contains properties that are not in this style.
+        // Starting a link ends the previous one, but ending a link without starting another one
+        // needs the reset too.
         if (from.fg.is_some() && self.fg.is_none())
             || (from.bg.is_some() && self.bg.is_none())
             || (from.bold && !self.bold)
-            || (!self.style_flags.0 & from.style_flags.0) != 0
+            || (!flags.0 & from_flags.0) != 0
+            || (from.hyperlink.is_some() && self.hyperlink.is_none())
         {
-            return Transition::FullReset(self);
+            return StyleTransition::FullReset { from, to: self };
         }
 
         // Build up a transition style, that does not require a full reset
@@ -141,37 +166,68 @@ impl<'a> Style {
             _ => None,
         };
 
+        // Removing an underline color doesn't need a full reset: the default underline color
+        // (59) can be set instead.
+        let underline_color = match (self.underline_color, from.underline_color) {
+            (Some(color), Some(from_color)) if color != from_color => Some(color),
+            (Some(color), None) => Some(color),
+            (None, Some(_)) => Some(DynColors::Ansi(AnsiColors::Default)),
+            _ => None,
+        };
+
         let new_style = Style {
             fg,
             bg,
+            underline_color,
             bold: from.bold ^ self.bold,
-            style_flags: StyleFlags(self.style_flags.0 ^ from.style_flags.0),
+            style_flags: StyleFlags(flags.0 ^ from_flags.0)
+                .set_font(self.style_flags.font_transition(&from.style_flags)),
+            hyperlink: if self.hyperlink != from.hyperlink {
+                self.hyperlink
+            } else {
+                None
+            },
         };
 
-        Transition::Style(new_style)
+        StyleTransition::Style(new_style)
     }
 }
 
-/// How the transition between two styles should be printed
-#[cfg_attr(test, derive(Debug, PartialEq))]
-enum Transition<'a> {
+/// How to switch from text displayed with one style to text displayed with another, returned by
+/// [`Style::transition_from`]. Displaying it writes the codes needed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[must_use = "this transition does nothing unless displayed"]
+pub enum StyleTransition<'a> {
+    /// The styles are the same, so nothing needs to be written.
     Noop,
-    FullReset(&'a Style),
+    /// The `from` style applies something that the `to` style doesn't, so the `from` style has
+    /// to be reset before applying the `to` style. The reset only turns off what the `from` style
+    /// applied if granular resets are enabled with [`set_reset_mode`](crate::set_reset_mode), and
+    /// ends the `from` style's link if it has one.
+    FullReset {
+        /// The style being switched from
+        from: &'a Style,
+        /// The style being switched to
+        to: &'a Style,
+    },
+    /// The colors and effects to apply on top of the `from` style to get the `to` style, and
+    /// the link to start if it's different.
     Style(Style),
 }
 
-impl fmt::Display for Transition<'_> {
+impl fmt::Display for StyleTransition<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             // Styles are equal
-            Transition::Noop => Ok(()),
-            // Reset the style & print full prefix
-            Transition::FullReset(style) => {
-                write!(f, "\x1B[0m")?;
-                style.fmt_prefix(f)
+            StyleTransition::Noop => Ok(()),
+            // Reset the style & print full prefix. The reset only turns off what the previous
+            // style set if granular resets are enabled.
+            StyleTransition::FullReset { from, to } => {
+                from.fmt_suffix(f)?;
+                to.fmt_prefix(f)
             }
             // Print transition style without resetting the style
-            Transition::Style(style) => style.fmt_prefix(f),
+            StyleTransition::Style(style) => style.fmt_prefix(f),
         }
     }
 }
@@ -183,6 +239,7 @@ pub type StyledVec<T> = StyledList<alloc::vec::Vec<Styled<T>>, Styled<T>>;
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Font;
 
     #[test]
     fn test_styled_list() {
@@ -221,7 +278,10 @@ mod test {
         let style_current = Style::new().italic().red();
         let style_prev = Style::new().italic().red();
 
-        assert_eq!(style_current.transition_from(&style_prev), Transition::Noop);
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Noop
+        );
     }
 
     #[test]
@@ -231,21 +291,30 @@ mod test {
 
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::FullReset(&style_current)
+            StyleTransition::FullReset {
+                from: &style_prev,
+                to: &style_current
+            }
         );
 
         let style_current = Style::new();
         let style_prev = Style::new().red();
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::FullReset(&style_current)
+            StyleTransition::FullReset {
+                from: &style_prev,
+                to: &style_current
+            }
         );
 
         let style_current = Style::new();
         let style_prev = Style::new().bold();
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::FullReset(&style_current)
+            StyleTransition::FullReset {
+                from: &style_prev,
+                to: &style_current
+            }
         );
     }
 
@@ -256,21 +325,88 @@ mod test {
 
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::Style(Style::new().dimmed())
+            StyleTransition::Style(Style::new().dimmed())
         );
 
         let style_current = Style::new().red().on_green();
         let style_prev = Style::new().red().on_bright_cyan();
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::Style(Style::new().on_green())
+            StyleTransition::Style(Style::new().on_green())
         );
 
         let style_current = Style::new().bold().blue();
         let style_prev = Style::new().bold();
         assert_eq!(
             style_current.transition_from(&style_prev),
-            Transition::Style(Style::new().blue())
+            StyleTransition::Style(Style::new().blue())
+        );
+    }
+
+    #[test]
+    fn test_transition_font() {
+        let style_current = Style::new().font(Font::Alt3);
+        let style_prev = Style::new().font(Font::Alt1);
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().font(Font::Alt3))
+        );
+
+        let style_current = Style::new().font(Font::Alt1).bold();
+        let style_prev = Style::new().font(Font::Alt1);
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().bold())
+        );
+
+        // Going back to the primary font doesn't need a reset
+        let style_current = Style::new().italic();
+        let style_prev = Style::new().italic().font(Font::Alt5);
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().font(Font::Primary))
+        );
+
+        let style_current = Style::new().fraktur();
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::FullReset {
+                from: &style_prev,
+                to: &style_current
+            }
+        );
+        let style_current = Style::new().italic().fraktur();
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().fraktur())
+        );
+    }
+
+    #[test]
+    fn test_transition_underline_color() {
+        let style_current = Style::new().underline().underline_color(AnsiColors::Red);
+        let style_prev = Style::new().underline().underline_color(AnsiColors::Blue);
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().underline_color(AnsiColors::Red))
+        );
+
+        let style_current = Style::new().underline();
+        assert_eq!(
+            style_current.transition_from(&style_prev),
+            StyleTransition::Style(Style::new().underline_color(AnsiColors::Default))
+        );
+
+        let list = &[
+            Style::new()
+                .underline()
+                .underline_color(AnsiColors::Red)
+                .style("a"),
+            Style::new().underline().style("b"),
+        ];
+        assert_eq!(
+            format!("{}", StyledList::from(list)),
+            "\x1b[58;5;1;4ma\x1b[59mb\x1b[0m"
         );
     }
 }
@@ -3,16 +3,17 @@ use core::fmt;
 
 #[allow(unused_imports)]
 use crate::OwoColorize;
-use crate::{Style, Styled};
+use crate::resets::is_granular;
+use crate::{Font, Style, Styled};
 
 macro_rules! impl_fmt_for_style {
-    ($(($ty:ident, $trait:path, $ansi:literal)),* $(,)?) => {
+    ($(($ty:ident, $trait:path, $ansi:literal, $reset:literal)),* $(,)?) => {
         $(
             impl<'a, T: ?Sized + $trait> $trait for $ty<'a, T> {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f.write_str($ansi)?;
                     <_ as $trait>::fmt(&self.0, f)?;
-                    f.write_str("\x1b[0m")
+                    f.write_str(if is_granular() { $reset } else { "\x1b[0m" })
                 }
             }
         )*
@@ -376,103 +377,682 @@ impl<'a, T: ?Sized> StrikeThroughDisplay<'a, T> {
     }
 }
 
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// double underlined. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::double_underline).
+#[repr(transparent)]
+pub struct DoubleUnderlineDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> DoubleUnderlineDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_double_underline() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_double_underline() {
+    ///     "hello".double_underline().into_styled()
+    /// } else {
+    ///     "hello".underline().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[4:2mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().double_underline();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// underlined with a curly line. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::curly_underline).
+#[repr(transparent)]
+pub struct CurlyUnderlineDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> CurlyUnderlineDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_curly_underline() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_curly_underline() {
+    ///     "hello".curly_underline().into_styled()
+    /// } else {
+    ///     "hello".underline().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[4:3mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().curly_underline();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// underlined with a dotted line. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::dotted_underline).
+#[repr(transparent)]
+pub struct DottedUnderlineDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> DottedUnderlineDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_dotted_underline() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_dotted_underline() {
+    ///     "hello".dotted_underline().into_styled()
+    /// } else {
+    ///     "hello".underline().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[4:4mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().dotted_underline();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// underlined with a dashed line. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::dashed_underline).
+#[repr(transparent)]
+pub struct DashedUnderlineDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> DashedUnderlineDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_dashed_underline() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_dashed_underline() {
+    ///     "hello".dashed_underline().into_styled()
+    /// } else {
+    ///     "hello".underline().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[4:5mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().dashed_underline();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// with a line above it. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::overline).
+#[repr(transparent)]
+pub struct OverlineDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> OverlineDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_overline() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_overline() {
+    ///     "hello".overline().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[53mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().overline();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// with a frame around it. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::framed).
+#[repr(transparent)]
+pub struct FramedDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> FramedDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_framed() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_framed() {
+    ///     "hello".framed().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[51mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().framed();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// with a circle around it. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::encircled).
+#[repr(transparent)]
+pub struct EncircledDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> EncircledDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_encircled() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_encircled() {
+    ///     "hello".encircled().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[52mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().encircled();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// as superscript. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::superscript).
+#[repr(transparent)]
+pub struct SuperscriptDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> SuperscriptDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_superscript() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_superscript() {
+    ///     "hello".superscript().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[73mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().superscript();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// as subscript. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::subscript).
+#[repr(transparent)]
+pub struct SubscriptDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> SubscriptDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_subscript() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_subscript() {
+    ///     "hello".subscript().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[74mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().subscript();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// in a Fraktur font. Recommended to be constructed using
+/// [`OwoColorize`](OwoColorize::fraktur).
+#[repr(transparent)]
+pub struct FrakturDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> FrakturDisplay<'a, T> {
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::OwoColorize;
+    ///
+    /// fn is_fraktur() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_fraktur() {
+    ///     "hello".fraktur().into_styled()
+    /// } else {
+    ///     "hello".italic().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[20mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().fraktur();
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+/// Transparent wrapper around a type which implements all the formatters the wrapped type does,
+/// in an alternate font. Recommended to be constructed using [`OwoColorize`](OwoColorize::font).
+pub struct FontDisplay<'a, T: ?Sized>(&'a T, Font);
+
+impl<'a, T: ?Sized> FontDisplay<'a, T> {
+    /// Create a new [`FontDisplay`], displaying `thing` in `font`.
+    pub const fn new(thing: &'a T, font: Font) -> Self {
+        Self(thing, font)
+    }
+
+    /// Convert self to a generic [`Styled`].
+    ///
+    /// This method erases color-related type parameters, and can be
+    /// used to unify types across branches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use owo_colors::{Font, OwoColorize};
+    ///
+    /// fn is_fancy() -> bool {
+    ///     // ...
+    ///     # true
+    /// }
+    ///
+    /// let styled_str = if is_fancy() {
+    ///     "hello".font(Font::Alt2).into_styled()
+    /// } else {
+    ///     "hello".fraktur().into_styled()
+    /// };
+    ///
+    /// println!("{}", styled_str);
+    /// # assert_eq!(styled_str.to_string(), "\x1b[12mhello\x1b[0m");
+    /// ```
+    pub const fn into_styled(self) -> Styled<&'a T> {
+        let style = Style::new().font(self.1);
+        Styled {
+            style,
+            target: self.0,
+        }
+    }
+}
+
+macro_rules! impl_fmt_for_font {
+    ($($trait:path),* $(,)?) => {
+        $(
+            impl<'a, T: ?Sized + $trait> $trait for FontDisplay<'a, T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "\x1b[1{}m", self.1.number())?;
+                    <_ as $trait>::fmt(&self.0, f)?;
+                    f.write_str(if is_granular() { "\x1b[10m" } else { "\x1b[0m" })
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_for_font! {
+    fmt::Display,
+    fmt::Debug,
+    fmt::UpperHex,
+    fmt::LowerHex,
+    fmt::Binary,
+    fmt::UpperExp,
+    fmt::LowerExp,
+    fmt::Octal,
+    fmt::Pointer,
+}
+
 impl_fmt_for_style! {
     // Bold
-    (BoldDisplay, fmt::Display,  "\x1b[1m"),
-    (BoldDisplay, fmt::Debug,    "\x1b[1m"),
-    (BoldDisplay, fmt::UpperHex, "\x1b[1m"),
-    (BoldDisplay, fmt::LowerHex, "\x1b[1m"),
-    (BoldDisplay, fmt::Binary,   "\x1b[1m"),
-    (BoldDisplay, fmt::UpperExp, "\x1b[1m"),
-    (BoldDisplay, fmt::LowerExp, "\x1b[1m"),
-    (BoldDisplay, fmt::Octal,    "\x1b[1m"),
-    (BoldDisplay, fmt::Pointer,  "\x1b[1m"),
+    (BoldDisplay, fmt::Display,  "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::Debug,    "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::UpperHex, "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::LowerHex, "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::Binary,   "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::UpperExp, "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::LowerExp, "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::Octal,    "\x1b[1m", "\x1b[22m"),
+    (BoldDisplay, fmt::Pointer,  "\x1b[1m", "\x1b[22m"),
 
     // Dim
-    (DimDisplay, fmt::Display,  "\x1b[2m"),
-    (DimDisplay, fmt::Debug,    "\x1b[2m"),
-    (DimDisplay, fmt::UpperHex, "\x1b[2m"),
-    (DimDisplay, fmt::LowerHex, "\x1b[2m"),
-    (DimDisplay, fmt::Binary,   "\x1b[2m"),
-    (DimDisplay, fmt::UpperExp, "\x1b[2m"),
-    (DimDisplay, fmt::LowerExp, "\x1b[2m"),
-    (DimDisplay, fmt::Octal,    "\x1b[2m"),
-    (DimDisplay, fmt::Pointer,  "\x1b[2m"),
+    (DimDisplay, fmt::Display,  "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::Debug,    "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::UpperHex, "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::LowerHex, "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::Binary,   "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::UpperExp, "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::LowerExp, "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::Octal,    "\x1b[2m", "\x1b[22m"),
+    (DimDisplay, fmt::Pointer,  "\x1b[2m", "\x1b[22m"),
 
     // Italic
-    (ItalicDisplay, fmt::Display,  "\x1b[3m"),
-    (ItalicDisplay, fmt::Debug,    "\x1b[3m"),
-    (ItalicDisplay, fmt::UpperHex, "\x1b[3m"),
-    (ItalicDisplay, fmt::LowerHex, "\x1b[3m"),
-    (ItalicDisplay, fmt::Binary,   "\x1b[3m"),
-    (ItalicDisplay, fmt::UpperExp, "\x1b[3m"),
-    (ItalicDisplay, fmt::LowerExp, "\x1b[3m"),
-    (ItalicDisplay, fmt::Octal,    "\x1b[3m"),
-    (ItalicDisplay, fmt::Pointer,  "\x1b[3m"),
+    (ItalicDisplay, fmt::Display,  "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::Debug,    "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::UpperHex, "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::LowerHex, "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::Binary,   "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::UpperExp, "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::LowerExp, "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::Octal,    "\x1b[3m", "\x1b[23m"),
+    (ItalicDisplay, fmt::Pointer,  "\x1b[3m", "\x1b[23m"),
 
     // Underline
-    (UnderlineDisplay, fmt::Display,  "\x1b[4m"),
-    (UnderlineDisplay, fmt::Debug,    "\x1b[4m"),
-    (UnderlineDisplay, fmt::UpperHex, "\x1b[4m"),
-    (UnderlineDisplay, fmt::LowerHex, "\x1b[4m"),
-    (UnderlineDisplay, fmt::Binary,   "\x1b[4m"),
-    (UnderlineDisplay, fmt::UpperExp, "\x1b[4m"),
-    (UnderlineDisplay, fmt::LowerExp, "\x1b[4m"),
-    (UnderlineDisplay, fmt::Octal,    "\x1b[4m"),
-    (UnderlineDisplay, fmt::Pointer,  "\x1b[4m"),
+    (UnderlineDisplay, fmt::Display,  "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::Debug,    "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::UpperHex, "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::LowerHex, "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::Binary,   "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::UpperExp, "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::LowerExp, "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::Octal,    "\x1b[4m", "\x1b[24m"),
+    (UnderlineDisplay, fmt::Pointer,  "\x1b[4m", "\x1b[24m"),
 
     // Blink
-    (BlinkDisplay, fmt::Display,  "\x1b[5m"),
-    (BlinkDisplay, fmt::Debug,    "\x1b[5m"),
-    (BlinkDisplay, fmt::UpperHex, "\x1b[5m"),
-    (BlinkDisplay, fmt::LowerHex, "\x1b[5m"),
-    (BlinkDisplay, fmt::Binary,   "\x1b[5m"),
-    (BlinkDisplay, fmt::UpperExp, "\x1b[5m"),
-    (BlinkDisplay, fmt::LowerExp, "\x1b[5m"),
-    (BlinkDisplay, fmt::Octal,    "\x1b[5m"),
-    (BlinkDisplay, fmt::Pointer,  "\x1b[5m"),
+    (BlinkDisplay, fmt::Display,  "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::Debug,    "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::UpperHex, "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::LowerHex, "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::Binary,   "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::UpperExp, "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::LowerExp, "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::Octal,    "\x1b[5m", "\x1b[25m"),
+    (BlinkDisplay, fmt::Pointer,  "\x1b[5m", "\x1b[25m"),
 
     // Blink fast
-    (BlinkFastDisplay, fmt::Display,  "\x1b[6m"),
-    (BlinkFastDisplay, fmt::Debug,    "\x1b[6m"),
-    (BlinkFastDisplay, fmt::UpperHex, "\x1b[6m"),
-    (BlinkFastDisplay, fmt::LowerHex, "\x1b[6m"),
-    (BlinkFastDisplay, fmt::Binary,   "\x1b[6m"),
-    (BlinkFastDisplay, fmt::UpperExp, "\x1b[6m"),
-    (BlinkFastDisplay, fmt::LowerExp, "\x1b[6m"),
-    (BlinkFastDisplay, fmt::Octal,    "\x1b[6m"),
-    (BlinkFastDisplay, fmt::Pointer,  "\x1b[6m"),
+    (BlinkFastDisplay, fmt::Display,  "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::Debug,    "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::UpperHex, "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::LowerHex, "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::Binary,   "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::UpperExp, "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::LowerExp, "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::Octal,    "\x1b[6m", "\x1b[25m"),
+    (BlinkFastDisplay, fmt::Pointer,  "\x1b[6m", "\x1b[25m"),
 
     // Reverse video
-    (ReversedDisplay, fmt::Display,  "\x1b[7m"),
-    (ReversedDisplay, fmt::Debug,    "\x1b[7m"),
-    (ReversedDisplay, fmt::UpperHex, "\x1b[7m"),
-    (ReversedDisplay, fmt::LowerHex, "\x1b[7m"),
-    (ReversedDisplay, fmt::Binary,   "\x1b[7m"),
-    (ReversedDisplay, fmt::UpperExp, "\x1b[7m"),
-    (ReversedDisplay, fmt::LowerExp, "\x1b[7m"),
-    (ReversedDisplay, fmt::Octal,    "\x1b[7m"),
-    (ReversedDisplay, fmt::Pointer,  "\x1b[7m"),
+    (ReversedDisplay, fmt::Display,  "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::Debug,    "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::UpperHex, "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::LowerHex, "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::Binary,   "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::UpperExp, "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::LowerExp, "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::Octal,    "\x1b[7m", "\x1b[27m"),
+    (ReversedDisplay, fmt::Pointer,  "\x1b[7m", "\x1b[27m"),
 
     // Hide the text
-    (HiddenDisplay, fmt::Display,  "\x1b[8m"),
-    (HiddenDisplay, fmt::Debug,    "\x1b[8m"),
-    (HiddenDisplay, fmt::UpperHex, "\x1b[8m"),
-    (HiddenDisplay, fmt::LowerHex, "\x1b[8m"),
-    (HiddenDisplay, fmt::Binary,   "\x1b[8m"),
-    (HiddenDisplay, fmt::UpperExp, "\x1b[8m"),
-    (HiddenDisplay, fmt::LowerExp, "\x1b[8m"),
-    (HiddenDisplay, fmt::Octal,    "\x1b[8m"),
-    (HiddenDisplay, fmt::Pointer,  "\x1b[8m"),
+    (HiddenDisplay, fmt::Display,  "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::Debug,    "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::UpperHex, "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::LowerHex, "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::Binary,   "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::UpperExp, "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::LowerExp, "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::Octal,    "\x1b[8m", "\x1b[28m"),
+    (HiddenDisplay, fmt::Pointer,  "\x1b[8m", "\x1b[28m"),
 
     // StrikeThrough
-    (StrikeThroughDisplay, fmt::Display,  "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::Debug,    "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::UpperHex, "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::LowerHex, "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::Binary,   "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::UpperExp, "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::LowerExp, "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::Octal,    "\x1b[9m"),
-    (StrikeThroughDisplay, fmt::Pointer,  "\x1b[9m"),
+    (StrikeThroughDisplay, fmt::Display,  "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::Debug,    "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::UpperHex, "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::LowerHex, "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::Binary,   "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::UpperExp, "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::LowerExp, "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::Octal,    "\x1b[9m", "\x1b[29m"),
+    (StrikeThroughDisplay, fmt::Pointer,  "\x1b[9m", "\x1b[29m"),
+
+    // Double underline
+    (DoubleUnderlineDisplay, fmt::Display,  "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::Debug,    "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::UpperHex, "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::LowerHex, "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::Binary,   "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::UpperExp, "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::LowerExp, "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::Octal,    "\x1b[4:2m", "\x1b[24m"),
+    (DoubleUnderlineDisplay, fmt::Pointer,  "\x1b[4:2m", "\x1b[24m"),
+
+    // Curly underline
+    (CurlyUnderlineDisplay, fmt::Display,  "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::Debug,    "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::UpperHex, "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::LowerHex, "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::Binary,   "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::UpperExp, "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::LowerExp, "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::Octal,    "\x1b[4:3m", "\x1b[24m"),
+    (CurlyUnderlineDisplay, fmt::Pointer,  "\x1b[4:3m", "\x1b[24m"),
+
+    // Dotted underline
+    (DottedUnderlineDisplay, fmt::Display,  "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::Debug,    "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::UpperHex, "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::LowerHex, "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::Binary,   "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::UpperExp, "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::LowerExp, "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::Octal,    "\x1b[4:4m", "\x1b[24m"),
+    (DottedUnderlineDisplay, fmt::Pointer,  "\x1b[4:4m", "\x1b[24m"),
+
+    // Dashed underline
+    (DashedUnderlineDisplay, fmt::Display,  "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::Debug,    "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::UpperHex, "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::LowerHex, "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::Binary,   "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::UpperExp, "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::LowerExp, "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::Octal,    "\x1b[4:5m", "\x1b[24m"),
+    (DashedUnderlineDisplay, fmt::Pointer,  "\x1b[4:5m", "\x1b[24m"),
+
+    // Overline
+    (OverlineDisplay, fmt::Display,  "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::Debug,    "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::UpperHex, "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::LowerHex, "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::Binary,   "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::UpperExp, "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::LowerExp, "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::Octal,    "\x1b[53m", "\x1b[55m"),
+    (OverlineDisplay, fmt::Pointer,  "\x1b[53m", "\x1b[55m"),
+
+    // Framed
+    (FramedDisplay, fmt::Display,  "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::Debug,    "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::UpperHex, "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::LowerHex, "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::Binary,   "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::UpperExp, "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::LowerExp, "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::Octal,    "\x1b[51m", "\x1b[54m"),
+    (FramedDisplay, fmt::Pointer,  "\x1b[51m", "\x1b[54m"),
+
+    // Encircled
+    (EncircledDisplay, fmt::Display,  "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::Debug,    "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::UpperHex, "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::LowerHex, "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::Binary,   "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::UpperExp, "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::LowerExp, "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::Octal,    "\x1b[52m", "\x1b[54m"),
+    (EncircledDisplay, fmt::Pointer,  "\x1b[52m", "\x1b[54m"),
+
+    // Superscript
+    (SuperscriptDisplay, fmt::Display,  "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::Debug,    "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::UpperHex, "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::LowerHex, "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::Binary,   "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::UpperExp, "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::LowerExp, "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::Octal,    "\x1b[73m", "\x1b[75m"),
+    (SuperscriptDisplay, fmt::Pointer,  "\x1b[73m", "\x1b[75m"),
+
+    // Subscript
+    (SubscriptDisplay, fmt::Display,  "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::Debug,    "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::UpperHex, "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::LowerHex, "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::Binary,   "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::UpperExp, "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::LowerExp, "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::Octal,    "\x1b[74m", "\x1b[75m"),
+    (SubscriptDisplay, fmt::Pointer,  "\x1b[74m", "\x1b[75m"),
+
+    // Fraktur
+    (FrakturDisplay, fmt::Display,  "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::Debug,    "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::UpperHex, "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::LowerHex, "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::Binary,   "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::UpperExp, "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::LowerExp, "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::Octal,    "\x1b[20m", "\x1b[23m"),
+    (FrakturDisplay, fmt::Pointer,  "\x1b[20m", "\x1b[23m"),
 }
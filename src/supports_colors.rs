@@ -22,7 +22,8 @@ pub enum Stream {
     Stderr,
 }
 
-use crate::OVERRIDE;
+use crate::{ColorDepth, DynColors, OVERRIDE, Palette};
+use core::cell::Cell;
 
 impl From<supports_color::Stream> for Stream {
     fn from(stream: supports_color::Stream) -> Self {
@@ -52,11 +53,11 @@ macro_rules! impl_fmt_for {
             {
                 #[inline(always)]
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    let (force_enabled, force_disabled) = OVERRIDE.is_force_enabled_or_disabled();
-                    if force_enabled || (on_cached(self.2) && !force_disabled) {
-                        <Out as $trait>::fmt(&self.1(self.0), f)
-                    } else {
-                        <In as $trait>::fmt(self.0, f)
+                    match color_depth(self.2) {
+                        Some(depth) => {
+                            with_color_depth(depth, || <Out as $trait>::fmt(&self.1(self.0), f))
+                        }
+                        None => <In as $trait>::fmt(self.0, f),
                     }
                 }
             }
@@ -64,14 +65,110 @@ macro_rules! impl_fmt_for {
     };
 }
 
-fn on_cached(stream: Stream) -> bool {
+/// Returns the color depth to display colors at on the given stream, taking overrides into
+/// account, or `None` if colors shouldn't be displayed.
+pub(crate) fn color_depth(stream: Stream) -> Option<ColorDepth> {
+    let (force_enabled, force_disabled) = OVERRIDE.is_force_enabled_or_disabled();
+    let depth = on_cached(stream);
+    if force_enabled || (depth.is_some() && !force_disabled) {
+        Some(depth.unwrap_or(ColorDepth::TrueColor))
+    } else {
+        None
+    }
+}
+
+/// Returns the color depth supported by the given stream, or `None` if it doesn't support colors.
+fn on_cached(stream: Stream) -> Option<ColorDepth> {
     let stream = match stream {
         Stream::Stdout => supports_color::Stream::Stdout,
         Stream::Stderr => supports_color::Stream::Stderr,
     };
-    supports_color::on_cached(stream)
-        .map(|level| level.has_basic)
-        .unwrap_or(false)
+    supports_color::on_cached(stream).and_then(|level| {
+        if level.has_16m {
+            Some(ColorDepth::TrueColor)
+        } else if level.has_256 {
+            Some(ColorDepth::Xterm256)
+        } else if level.has_basic {
+            Some(ColorDepth::Ansi16)
+        } else {
+            None
+        }
+    })
+}
+
+std::thread_local! {
+    /// The color depth of the stream currently being displayed on this thread. Colors are not
+    /// downgraded outside of `if_supports_color`.
+    static COLOR_DEPTH: Cell<ColorDepth> = const { Cell::new(ColorDepth::TrueColor) };
+}
+
+/// Runs `f` with colors downgraded to `depth`, restoring the previous depth afterwards (even if `f`
+/// panics).
+pub(crate) fn with_color_depth<T>(depth: ColorDepth, f: impl FnOnce() -> T) -> T {
+    let previous = COLOR_DEPTH.with(|current| current.replace(depth));
+    let _guard = ResetColorDepthGuard { previous };
+
+    f()
+}
+
+struct ResetColorDepthGuard {
+    previous: ColorDepth,
+}
+
+impl Drop for ResetColorDepthGuard {
+    fn drop(&mut self) {
+        COLOR_DEPTH.with(|current| current.set(self.previous));
+    }
+}
+
+std::thread_local! {
+    /// The palette colors are matched against when they're downgraded on this thread.
+    static PALETTE: Cell<Palette> = const { Cell::new(Palette::DEFAULT) };
+}
+
+/// Matches colors against `palette` when
+/// [`if_supports_color`](crate::OwoColorize::if_supports_color) downgrades them to the 16 ANSI
+/// colors, on the current thread while `f` runs.
+///
+/// By default, colors are matched against [`Palette::DEFAULT`], which may pick a color that looks
+/// different to the original on terminals with another color scheme. Once `f` returns (or
+/// panics), the previous palette is used again.
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, Palette, Stream, with_palette};
+///
+/// with_palette(Palette::SOLARIZED_DARK, || {
+///     // On a 16 color terminal, this is displayed as Solarized's blue rather than its cyan
+///     let blue = "blue".if_supports_color(Stream::Stdout, |text| text.truecolor(38, 139, 210));
+///     println!("{}", blue);
+/// });
+/// ```
+pub fn with_palette<T>(palette: Palette, f: impl FnOnce() -> T) -> T {
+    let previous = PALETTE.with(|current| current.replace(palette));
+    let _guard = ResetPaletteGuard { previous };
+
+    f()
+}
+
+struct ResetPaletteGuard {
+    previous: Palette,
+}
+
+impl Drop for ResetPaletteGuard {
+    fn drop(&mut self) {
+        PALETTE.with(|current| current.set(self.previous));
+    }
+}
+
+/// Returns the nearest color to `color` that can be displayed at the current color depth, or `None`
+/// if `color` can be displayed as-is.
+pub(crate) fn downgrade(color: DynColors) -> Option<DynColors> {
+    let downgraded = color.downgrade_with(COLOR_DEPTH.with(Cell::get), &PALETTE.with(Cell::get));
+    if downgraded == color {
+        None
+    } else {
+        Some(downgraded)
+    }
 }
 
 impl_fmt_for! {
@@ -88,7 +185,8 @@ impl_fmt_for! {
 
 #[cfg(test)]
 mod test {
-    use crate::OwoColorize;
+    use super::{with_color_depth, with_palette};
+    use crate::{ColorDepth, OwoColorize, Palette, Rgb, Style, XtermColors, colors::css::Orange};
 
     #[test]
     fn test_supports_color_versions() {
@@ -103,4 +201,74 @@ mod test {
             "This might be red".if_supports_color(supports_color::Stream::Stdout, |x| x.red())
         );
     }
+
+    #[test]
+    fn test_downgrade_xterm256() {
+        with_color_depth(ColorDepth::Xterm256, || {
+            assert_eq!(
+                "test".truecolor(255, 135, 0).to_string(),
+                "\x1b[38;5;208mtest\x1b[39m"
+            );
+            assert_eq!(
+                "test".on_color(Rgb(255, 0, 0)).to_string(),
+                "\x1b[48;5;196mtest\x1b[49m"
+            );
+            assert_eq!(
+                "test".color(XtermColors::FlushOrange).to_string(),
+                "\x1b[38;5;208mtest\x1b[39m"
+            );
+            assert_eq!(
+                "test".fg::<Orange>().to_string(),
+                "\x1b[38;5;214mtest\x1b[39m"
+            );
+        });
+    }
+
+    #[test]
+    fn test_downgrade_ansi16() {
+        with_color_depth(ColorDepth::Ansi16, || {
+            assert_eq!(
+                "test".truecolor(250, 10, 10).to_string(),
+                "\x1b[91mtest\x1b[39m"
+            );
+            assert_eq!(
+                "test".color(XtermColors::UserBlue).to_string(),
+                "\x1b[34mtest\x1b[39m"
+            );
+            assert_eq!(
+                "test".fg_rgb::<0, 0, 120>().on_red().to_string(),
+                "\x1b[34;41mtest\x1b[0m"
+            );
+            assert_eq!(
+                Style::new()
+                    .truecolor(0, 250, 0)
+                    .on_truecolor(0, 0, 0)
+                    .bold()
+                    .style("test")
+                    .to_string(),
+                "\x1b[92;40;1mtest\x1b[0m"
+            );
+        });
+    }
+
+    #[test]
+    fn test_downgrade_with_palette() {
+        with_color_depth(ColorDepth::Ansi16, || {
+            let blue = Rgb(38, 139, 210);
+            assert_eq!("test".color(blue).to_string(), "\x1b[36mtest\x1b[39m");
+            with_palette(Palette::SOLARIZED_DARK, || {
+                assert_eq!("test".color(blue).to_string(), "\x1b[34mtest\x1b[39m");
+            });
+            assert_eq!("test".color(blue).to_string(), "\x1b[36mtest\x1b[39m");
+        });
+    }
+
+    #[test]
+    fn test_no_downgrade_outside_stream() {
+        with_color_depth(ColorDepth::Ansi16, || {});
+        assert_eq!(
+            "test".truecolor(255, 135, 0).to_string(),
+            "\x1b[38;2;255;135;0mtest\x1b[39m"
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use super::OwoColorize;
 use super::colors::*;
 use crate::colors::css::Lavender;
-use crate::{AnsiColors, DynColors};
+use crate::{AnsiColors, CssColors, DynColors, XtermColors};
 
 #[test]
 fn test_fg() {
@@ -69,9 +69,120 @@ fn test_parse() {
         "yellow" == DynColors::Ansi(AnsiColors::Yellow),
         "blue" == DynColors::Ansi(AnsiColors::Blue),
         "#eb4034" == DynColors::Rgb(235, 64, 52),
+        "#EB4034" == DynColors::Rgb(235, 64, 52),
+        "#fff" == DynColors::Rgb(255, 255, 255),
+        "  #a1b  " == DynColors::Rgb(0xaa, 0x11, 0xbb),
+        "rgb(1, 2, 3)" == DynColors::Rgb(1, 2, 3),
+        "RGB( 1 2 3 )" == DynColors::Rgb(1, 2, 3),
+        "rgb(100%, 50%, 0%)" == DynColors::Rgb(255, 128, 0),
+        "hsl(0, 100%, 50%)" == DynColors::Rgb(255, 0, 0),
+        "hsl(-120deg, 100, 50)" == DynColors::Rgb(0, 0, 255),
+        "208" == DynColors::Xterm(XtermColors::FlushOrange),
+        "ansi( 0 )" == DynColors::Xterm(XtermColors::UserBlack),
+        "bright red" == DynColors::Ansi(AnsiColors::BrightRed),
+        "bright-red" == DynColors::Ansi(AnsiColors::BrightRed),
+        "BRIGHT_RED" == DynColors::Ansi(AnsiColors::BrightRed),
+        "purple" == DynColors::Ansi(AnsiColors::Magenta),
+        "default" == DynColors::Ansi(AnsiColors::Default),
+        "aliceblue" == DynColors::Css(CssColors::AliceBlue),
+        "Alice Blue" == DynColors::Css(CssColors::AliceBlue),
+        "rebecca-purple" == DynColors::Css(CssColors::RebeccaPurple),
     );
 }
 
+#[test]
+fn test_color_names() {
+    for &css in CssColors::ALL {
+        assert_eq!(css.name().parse(), Ok(css));
+        assert_eq!(DynColors::Css(css).to_rgb(), Some(css.rgb()));
+    }
+    for (index, &xterm) in XtermColors::ALL.iter().enumerate() {
+        assert_eq!(u8::from(xterm) as usize, index);
+        assert_eq!(xterm.name().parse(), Ok(xterm));
+        assert_eq!(index.to_string().parse(), Ok(xterm));
+    }
+
+    assert_eq!(" Dark_Slate-Gray ".parse(), Ok(CssColors::DarkSlateGray));
+    assert_eq!(
+        "256".parse::<XtermColors>().unwrap_err().kind(),
+        crate::ParseColorErrorKind::OutOfRange
+    );
+    assert_eq!(
+        "".parse::<XtermColors>().unwrap_err().kind(),
+        crate::ParseColorErrorKind::Empty
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    use crate::ParseColorErrorKind::*;
+
+    macro_rules! assert_parse_error {
+        ($($str:literal == ($kind:expr, $position:expr)),* $(,)?) => {
+            $(
+                let error = $str.parse::<DynColors>().unwrap_err();
+                assert_eq!((error.kind(), error.position()), ($kind, $position), "{:?}", $str);
+             )*
+        }
+    }
+
+    assert_parse_error!(
+        "" == (Empty, 0),
+        "   " == (Empty, 3),
+        "#ff00gg" == (InvalidHexDigit, 5),
+        " #ff00" == (InvalidHexLength, 1),
+        "#" == (InvalidHexLength, 0),
+        "not a color" == (UnknownName, 0),
+        "rgba(1, 2, 3, 4)" == (UnknownFunction, 0),
+        "rgb(1, 2, 3" == (UnclosedFunction, 11),
+        "rgb(1, 2, 3) x" == (UnclosedFunction, 14),
+        "rgb(1, (2), 3)" == (UnclosedFunction, 7),
+        "rgb(1, 2)" == (WrongArgumentCount, 8),
+        "rgb(1 2 3 4)" == (WrongArgumentCount, 10),
+        "rgb(1, , 3)" == (InvalidNumber, 7),
+        "rgb(1, x, 3)" == (InvalidNumber, 7),
+        "rgb(1, 2, 256)" == (OutOfRange, 10),
+        "rgb(1, 2, 101%)" == (OutOfRange, 10),
+        "hsl(nan, 0, 0)" == (InvalidNumber, 4),
+        "256" == (OutOfRange, 0),
+        "ansi(-1)" == (InvalidNumber, 5),
+    );
+
+    assert_eq!(
+        "rgb(1, 2, 256)"
+            .parse::<DynColors>()
+            .unwrap_err()
+            .to_string(),
+        "number out of range at position 10"
+    );
+    assert_eq!(
+        "#12".parse::<DynColors>(),
+        Err(crate::ParseColorError::new(InvalidHexLength, 0))
+    );
+}
+
+#[test]
+fn test_parse_multibyte_whitespace() {
+    assert_eq!("rgb(1\u{a0}2\u{a0}3)".parse(), Ok(DynColors::Rgb(1, 2, 3)));
+    assert_eq!(
+        "hsl(0\u{3000}100%\u{3000}50%)".parse(),
+        Ok(DynColors::Rgb(255, 0, 0))
+    );
+
+    // Multi-byte characters anywhere in the arguments must give an error rather than panic
+    for space in ["\u{a0}", "\u{2003}", "\u{3000}", "é"] {
+        for input in [
+            format!("rgb({space}1 2 3)"),
+            format!("rgb(1 2 3{space})"),
+            format!("rgb(1,{space}2,3)"),
+            format!("rgb(1{space}{space}2 3)"),
+            format!("ansi({space})"),
+        ] {
+            let _ = input.parse::<DynColors>();
+        }
+    }
+}
+
 #[test]
 fn default_color() {
     assert_eq!(
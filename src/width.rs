@@ -0,0 +1,159 @@
+//! Measuring the columns styled text takes up in a terminal.
+use crate::Styled;
+use crate::escapes::EscapeScanner;
+use crate::styled_list::{IsStyled, StyledList};
+use core::fmt::{self, Display, Write};
+
+/// Returns the number of terminal columns a value takes up when displayed, not counting escape
+/// sequences. For text with several lines, this is the width of the widest one.
+///
+/// With the `unicode-width` feature, characters are measured using their East Asian Width, so
+/// that wide characters such as CJK ideographs and most emoji count as two columns. Without it,
+/// every character counts as one column. Either way, control characters, combining marks and
+/// characters joined to the previous one by a zero width joiner don't take up any columns.
+///
+/// This is useful for aligning styled text, as padding with `{:>10}` pads each piece of styled
+/// text separately, and [`str::len`] counts the bytes of escape sequences.
+///
+/// ```rust
+/// use owo_colors::{OwoColorize, visible_width};
+///
+/// let cells = [format!("{}", "ok".green()), format!("{}", "failed".red().bold())];
+/// let width = cells.iter().map(visible_width).max().unwrap();
+/// assert_eq!(width, 6);
+///
+/// for cell in &cells {
+///     let padding = width - visible_width(cell);
+///     println!("{:padding$}{cell} |", "");
+/// }
+/// ```
+pub fn visible_width(value: &impl Display) -> usize {
+    let mut measure = Measure::default();
+    let _ = write!(measure, "{value}");
+    measure.widest
+}
+
+impl<T: Display> Styled<T> {
+    /// Returns the number of terminal columns this value takes up when displayed, not counting
+    /// escape sequences. See [`visible_width`] for how the text is measured.
+    ///
+    /// ```rust
+    /// use owo_colors::{OwoColorize, Style};
+    ///
+    /// assert_eq!("hello".style(Style::new().blue().underline()).visible_width(), 5);
+    /// ```
+    pub fn visible_width(&self) -> usize {
+        visible_width(&self.target)
+    }
+}
+
+impl<T, U> StyledList<T, U>
+where
+    T: AsRef<[U]>,
+    U: IsStyled,
+{
+    /// Returns the number of terminal columns the list takes up when displayed, not counting
+    /// escape sequences. See [`visible_width`] for how the text is measured.
+    ///
+    /// ```rust
+    /// use owo_colors::{Style, StyledList};
+    ///
+    /// let dimmed = Style::new().dimmed();
+    /// let list = StyledList::from([dimmed.style("["), Style::new().green().style("done"), dimmed.style("]")]);
+    /// assert_eq!(list.visible_width(), 6);
+    /// ```
+    pub fn visible_width(&self) -> usize {
+        visible_width(self)
+    }
+}
+
+/// Measures the widest line of written text.
+#[derive(Default)]
+struct Measure {
+    escapes: EscapeScanner,
+    column: usize,
+    widest: usize,
+    /// Whether the previous character was a zero width joiner
+    joined: bool,
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.escapes.feed(c) {
+                continue;
+            }
+
+            let joined = core::mem::replace(&mut self.joined, c == '\u{200d}');
+            if c == '\n' {
+                self.column = 0;
+            } else if !joined {
+                self.column += char_width(c);
+                self.widest = self.widest.max(self.column);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "unicode-width")]
+fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+#[cfg(not(feature = "unicode-width"))]
+fn char_width(c: char) -> usize {
+    if c.is_control() || crate::gradient::extends_grapheme(c) {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OwoColorize, Style};
+
+    #[test]
+    fn test_visible_width() {
+        assert_eq!(visible_width(&""), 0);
+        assert_eq!(visible_width(&"plain"), 5);
+        assert_eq!(visible_width(&"bold".bold().on_red()), 4);
+        assert_eq!(visible_width(&"link".hyperlink("https://example.com")), 4);
+        assert_eq!(visible_width(&"a\x1b[2Kb\x07\tc"), 3);
+        assert_eq!(visible_width(&"e\u{301}"), 1);
+        assert_eq!(visible_width(&"short\nlonger line\nmid".red()), 11);
+        assert_eq!(visible_width(&Style::new().italic().style(42)), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-width")]
+    fn test_east_asian_width() {
+        assert_eq!(visible_width(&"日本語".green()), 6);
+        assert_eq!(visible_width(&"🦀 ok"), 5);
+        assert_eq!(visible_width(&"👩\u{200d}💻"), 2);
+        assert_eq!(visible_width(&"ｱ"), 1);
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode-width"))]
+    fn test_char_count() {
+        assert_eq!(visible_width(&"日本語".green()), 3);
+        assert_eq!(visible_width(&"👩\u{200d}💻"), 1);
+    }
+
+    #[test]
+    fn test_styled_visible_width() {
+        assert_eq!(Style::new().red().style("wide").visible_width(), 4);
+        assert_eq!(
+            StyledList::from([
+                Style::new().red().style("a"),
+                Style::new().on_blue().style("bc"),
+                Style::new().bold().style(""),
+            ])
+            .visible_width(),
+            3
+        );
+    }
+}